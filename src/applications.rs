@@ -89,4 +89,59 @@ impl<'c> ApplicationsManager<'c> {
 
 		Ok(installed)
 	}
+
+	// ---- Scene Application ----
+
+	/// Gets a human readable name for `state`, such as `"Running"`.
+	///
+	/// Wraps c++ `GetSceneApplicationStateNameFromEnum`.
+	pub fn scene_application_state_name(
+		&mut self,
+		state: sys::EVRSceneApplicationState,
+	) -> String {
+		let name = unsafe {
+			CStr::from_ptr(
+				self.inner
+					.as_mut()
+					.GetSceneApplicationStateNameFromEnum(state),
+			)
+		};
+		name.to_string_lossy().into_owned()
+	}
+
+	/// Gets the process id of the current scene application, such as a game, or
+	/// `None` if no scene application is running.
+	///
+	/// Wraps c++ `GetCurrentSceneProcessId`.
+	pub fn get_current_scene_process_id(&mut self) -> Option<u32> {
+		let pid = unsafe { self.inner.as_mut().GetCurrentSceneProcessId() };
+		(pid != 0).then_some(pid)
+	}
+
+	/// Gets the key of the application running as process `pid`. Combined with
+	/// [`Self::get_current_scene_process_id()`], this identifies the running game.
+	///
+	/// Wraps c++ `GetApplicationKeyByProcessId`.
+	pub fn get_application_key_by_process_id(&mut self, pid: u32) -> Result<String> {
+		let mut key = vec![0u8; sys::k_unMaxApplicationKeyLength as usize];
+		let err = unsafe {
+			self.inner.as_mut().GetApplicationKeyByProcessId(
+				pid,
+				key.as_mut_ptr().cast(),
+				key.len() as u32,
+			)
+		};
+		EVRApplicationError::new(err)?;
+		// Don't trust openvr to have nul-terminated the key.
+		let key = if let Ok(s) = CStr::from_bytes_until_nul(&key) {
+			s
+		} else {
+			return EVRApplicationError::new(
+				sys::EVRApplicationError::VRApplicationError_BufferTooSmall,
+			)
+			.map(|_| unreachable!());
+		};
+		Ok(key.to_string_lossy().into_owned())
+	}
 }
+unsafe impl Send for ApplicationsManager<'_> {}
@@ -0,0 +1,50 @@
+use crate::pose::TrackingUniverseOrigin;
+use crate::{sys, Context};
+
+use std::marker::PhantomData;
+use std::pin::Pin;
+
+pub struct ChaperoneManager<'c> {
+	ctx: PhantomData<&'c Context>,
+	inner: Pin<&'c mut sys::IVRChaperone>,
+}
+
+impl<'c> ChaperoneManager<'c> {
+	pub(super) fn new(_ctx: &'c Context) -> Self {
+		let inner =
+			unsafe { Pin::new_unchecked(sys::VRChaperone().as_mut::<'c>().unwrap()) };
+		Self {
+			ctx: Default::default(),
+			inner,
+		}
+	}
+
+	/// Gets the size of the play area as `(x, z)` in meters, or `None` if it isn't
+	/// available.
+	pub fn play_area_size(&mut self) -> Option<(f32, f32)> {
+		let (mut x, mut z) = (0.0, 0.0);
+		let valid = unsafe { self.inner.as_mut().GetPlayAreaSize(&mut x, &mut z) };
+		valid.then_some((x, z))
+	}
+
+	/// Reloads the chaperone data from the runtime. Call this after committing
+	/// changes with [`crate::chaperone_setup::ChaperoneSetupManager`], or the live
+	/// data (such as [`Self::play_area_size()`]) stays stale.
+	pub fn reload_info(&mut self) {
+		unsafe { self.inner.as_mut().ReloadInfo() }
+	}
+
+	/// Recenters the seated universe on the current position and heading of the
+	/// headset. See [`crate::system::SystemManager::get_seated_zero_pose_to_standing()`]
+	/// for converting between the seated and standing universes.
+	///
+	/// Wraps c++ `ResetZeroPose`, which replaced `IVRSystem::ResetSeatedZeroPose`.
+	pub fn reset_seated_zero_pose(&mut self) {
+		unsafe {
+			self.inner
+				.as_mut()
+				.ResetZeroPose(TrackingUniverseOrigin::TrackingUniverseSeated)
+		}
+	}
+}
+unsafe impl Send for ChaperoneManager<'_> {}
@@ -1,6 +1,6 @@
 use crate::{sys, Context};
 
-use std::ffi::CString;
+use std::ffi::{CStr, CString};
 use std::marker::PhantomData;
 use std::pin::Pin;
 use std::ptr::null_mut;
@@ -21,7 +21,7 @@ impl<'c> ChaperoneSetupManager<'c> {
 		}
 	}
 
-	// TODO: this outputs json, could we pass it directly to something that does json?
+	/// Exports the live chaperone data as a json document.
 	pub fn export_live_to_buffer(&mut self) -> Option<CString> {
 		let mut len = 0u32;
 		// Passing null pointer here means it will merely write to the length parameter.
@@ -43,4 +43,69 @@ impl<'c> ChaperoneSetupManager<'c> {
 			None
 		}
 	}
+
+	/// Same as [`Self::export_live_to_buffer()`], but as a `String`. Invalid UTF-8
+	/// is replaced with `U+FFFD`.
+	pub fn export_live_to_string(&mut self) -> Option<String> {
+		self.export_live_to_buffer()
+			.map(|json| json.to_string_lossy().into_owned())
+	}
+
+	/// Same as [`Self::export_live_to_buffer()`], but parsed as json. Returns `None`
+	/// if there is no data, or if it isn't valid json.
+	#[cfg(feature = "serde")]
+	pub fn export_live_to_json(&mut self) -> Option<serde_json::Value> {
+		let json = self.export_live_to_buffer()?;
+		serde_json::from_slice(json.as_bytes()).ok()
+	}
+
+	/// Sets the size of the play area in the working copy, as `(x, z)` in meters.
+	/// Call [`Self::commit_working_copy()`] to apply it.
+	pub fn set_working_play_area_size(&mut self, size_x: f32, size_z: f32) {
+		unsafe { self.inner.as_mut().SetWorkingPlayAreaSize(size_x, size_z) }
+	}
+
+	/// Gets the size of the play area in the working copy as `(x, z)` in meters, or
+	/// `None` if it isn't available.
+	pub fn get_working_play_area_size(&mut self) -> Option<(f32, f32)> {
+		let (mut x, mut z) = (0.0, 0.0);
+		let valid =
+			unsafe { self.inner.as_mut().GetWorkingPlayAreaSize(&mut x, &mut z) };
+		valid.then_some((x, z))
+	}
+
+	/// Saves the working copy to `config`, making it the live data. Returns `false`
+	/// if it could not be saved.
+	pub fn commit_working_copy(&mut self, config: sys::EChaperoneConfigFile) -> bool {
+		unsafe { self.inner.as_mut().CommitWorkingCopy(config) }
+	}
+
+	/// Replaces the working copy with the live data, discarding any uncommitted
+	/// changes.
+	pub fn revert_working_copy(&mut self) {
+		unsafe { self.inner.as_mut().RevertWorkingCopy() }
+	}
+
+	/// Reloads `config` from disk, replacing both the working copy and the live
+	/// data.
+	pub fn reload_from_disk(&mut self, config: sys::EChaperoneConfigFile) {
+		unsafe { self.inner.as_mut().ReloadFromDisk(config) }
+	}
+
+	/// Loads chaperone data, such as the output of [`Self::export_live_to_buffer()`],
+	/// into the working copy. Returns `false` if it could not be imported.
+	///
+	/// `import_flags` is a bitmask of `sys::EChaperoneImportFlags`.
+	pub fn import_from_buffer_to_working(
+		&mut self,
+		json: &CStr,
+		import_flags: u32,
+	) -> bool {
+		unsafe {
+			self.inner
+				.as_mut()
+				.ImportFromBufferToWorking(json.as_ptr(), import_flags)
+		}
+	}
 }
+unsafe impl Send for ChaperoneSetupManager<'_> {}
@@ -27,7 +27,7 @@ impl EVRInitError {
 }
 impl Display for EVRInitError {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-		let num = self.0 as u8;
+		let num = self.0 as i32;
 		let desc = self.description();
 		write!(f, "EVRInitError({num}): {desc}")
 	}
@@ -45,7 +45,34 @@ impl EVROverlayError {
 	}
 
 	pub fn description(&self) -> &'static str {
-		"todo"
+		use sys::EVROverlayError::*;
+		match self.0 {
+			VROverlayError_None => "None",
+			VROverlayError_UnknownOverlay => "UnknownOverlay",
+			VROverlayError_InvalidHandle => "InvalidHandle",
+			VROverlayError_PermissionDenied => "PermissionDenied",
+			VROverlayError_OverlayLimitExceeded => "OverlayLimitExceeded",
+			VROverlayError_WrongVisibilityType => "WrongVisibilityType",
+			VROverlayError_KeyTooLong => "KeyTooLong",
+			VROverlayError_NameTooLong => "NameTooLong",
+			VROverlayError_KeyInUse => "KeyInUse",
+			VROverlayError_WrongTransformType => "WrongTransformType",
+			VROverlayError_InvalidTrackedDevice => "InvalidTrackedDevice",
+			VROverlayError_InvalidParameter => "InvalidParameter",
+			VROverlayError_ThumbnailCantBeDestroyed => "ThumbnailCantBeDestroyed",
+			VROverlayError_ArrayTooSmall => "ArrayTooSmall",
+			VROverlayError_RequestFailed => "RequestFailed",
+			VROverlayError_InvalidTexture => "InvalidTexture",
+			VROverlayError_UnableToLoadFile => "UnableToLoadFile",
+			VROverlayError_KeyboardAlreadyInUse => "KeyboardAlreadyInUse",
+			VROverlayError_NoNeighbor => "NoNeighbor",
+			VROverlayError_TooManyMaskPrimitives => "TooManyMaskPrimitives",
+			VROverlayError_BadMaskPrimitive => "BadMaskPrimitive",
+			VROverlayError_TextureAlreadyLocked => "TextureAlreadyLocked",
+			VROverlayError_TextureLockCapacityReached => "TextureLockCapacityReached",
+			VROverlayError_TextureNotLocked => "TextureNotLocked",
+			VROverlayError_TimedOut => "TimedOut",
+		}
 	}
 
 	pub fn inner(&self) -> sys::EVROverlayError {
@@ -54,7 +81,7 @@ impl EVROverlayError {
 }
 impl Display for EVROverlayError {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-		let num = self.0 as u8;
+		let num = self.0 as i32;
 		let desc = self.description();
 		write!(f, "EVROverlayError({num}): {desc}")
 	}
@@ -76,7 +103,25 @@ impl ETrackedPropertyError {
 	}
 
 	pub fn description(&self) -> &'static str {
-		"todo"
+		use sys::ETrackedPropertyError::*;
+		match self.0 {
+			TrackedProp_Success => "Success",
+			TrackedProp_WrongDataType => "WrongDataType",
+			TrackedProp_WrongDeviceClass => "WrongDeviceClass",
+			TrackedProp_BufferTooSmall => "BufferTooSmall",
+			TrackedProp_UnknownProperty => "UnknownProperty",
+			TrackedProp_InvalidDevice => "InvalidDevice",
+			TrackedProp_CouldNotContactServer => "CouldNotContactServer",
+			TrackedProp_ValueNotProvidedByDevice => "ValueNotProvidedByDevice",
+			TrackedProp_StringExceedsMaximumLength => "StringExceedsMaximumLength",
+			TrackedProp_NotYetAvailable => "NotYetAvailable",
+			TrackedProp_PermissionDenied => "PermissionDenied",
+			TrackedProp_InvalidOperation => "InvalidOperation",
+			TrackedProp_CannotWriteToWildcards => "CannotWriteToWildcards",
+			TrackedProp_IPCReadFailure => "IPCReadFailure",
+			TrackedProp_OutOfMemory => "OutOfMemory",
+			TrackedProp_InvalidContainer => "InvalidContainer",
+		}
 	}
 
 	pub fn inner(&self) -> sys::ETrackedPropertyError {
@@ -87,7 +132,7 @@ impl ETrackedPropertyError {
 #[cfg(feature = "ovr_system")]
 impl Display for ETrackedPropertyError {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-		let num = self.0 as u8;
+		let num = self.0 as i32;
 		let desc = self.description();
 		write!(f, "ETrackedPropertyError({num}): {desc}")
 	}
@@ -142,7 +187,7 @@ impl EVRInputError {
 #[cfg(feature = "ovr_input")]
 impl Display for EVRInputError {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-		let num = self.0 as u8;
+		let num = self.0 as i32;
 		let desc = self.description();
 		write!(f, "EVRInputError({num}): {desc}")
 	}
@@ -201,12 +246,66 @@ impl EVRApplicationError {
 #[cfg(feature = "ovr_applications")]
 impl Display for EVRApplicationError {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-		let num = self.0 as u8;
+		let num = self.0 as i32;
 		let desc = self.description();
 		write!(f, "EVRApplicationError({num}): {desc}")
 	}
 }
 
+#[cfg(feature = "ovr_settings")]
+#[derive(Into, Debug, Copy, Clone, PartialEq, Eq, thiserror::Error)]
+#[repr(transparent)]
+pub struct EVRSettingsError(sys::EVRSettingsError);
+
+#[cfg(feature = "ovr_settings")]
+impl EVRSettingsError {
+	pub fn new(err: sys::EVRSettingsError) -> Result<(), Self> {
+		if err == sys::EVRSettingsError::VRSettingsError_None {
+			Ok(())
+		} else {
+			Err(Self(err))
+		}
+	}
+
+	pub fn description(&self) -> &'static str {
+		use sys::EVRSettingsError::*;
+		match self.0 {
+			VRSettingsError_None => "None",
+			VRSettingsError_IPCFailed => "IPCFailed",
+			VRSettingsError_WriteFailed => "WriteFailed",
+			VRSettingsError_ReadFailed => "ReadFailed",
+			VRSettingsError_JsonParseFailed => "JsonParseFailed",
+			VRSettingsError_UnsetSettingHasNoDefault => "UnsetSettingHasNoDefault",
+			VRSettingsError_AccessDenied => "AccessDenied",
+		}
+	}
+
+	pub fn inner(&self) -> sys::EVRSettingsError {
+		self.0
+	}
+}
+
+#[cfg(feature = "ovr_settings")]
+impl Display for EVRSettingsError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		let num = self.0 as i32;
+		let desc = self.description();
+		write!(f, "EVRSettingsError({num}): {desc}")
+	}
+}
+
+/// Error from [`crate::overlay::OverlayManager::set_image_from_bytes()`].
+#[cfg(feature = "image")]
+#[derive(Debug, thiserror::Error)]
+pub enum SetImageError {
+	/// The bytes aren't a PNG or JPEG image. OpenVR would report this as
+	/// `VROverlayError_InvalidParameter`, but this keeps the reason.
+	#[error("failed to decode overlay image")]
+	Decode(#[from] image::ImageError),
+	#[error("overlay error")]
+	Overlay(#[from] EVROverlayError),
+}
+
 #[derive(Debug, From, thiserror::Error)]
 pub enum InitError {
 	#[error("OpenVR already initialized")]
@@ -214,3 +313,62 @@ pub enum InitError {
 	#[error("sys::{0}")]
 	Sys(EVRInitError),
 }
+
+/// Unified error type covering every subsystem of the crate.
+///
+/// Each subsystem error converts into this via [`From`], so `?` works across
+/// managers. The subsystem error is exposed as the [`std::error::Error::source()`].
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+	#[error("failed to initialize OpenVR")]
+	Init(#[from] InitError),
+	#[error("overlay error")]
+	Overlay(#[from] EVROverlayError),
+	#[cfg(feature = "ovr_system")]
+	#[error("tracked property error")]
+	TrackedProperty(#[from] ETrackedPropertyError),
+	#[cfg(feature = "ovr_input")]
+	#[error("input error")]
+	Input(#[from] EVRInputError),
+	#[cfg(feature = "ovr_applications")]
+	#[error("applications error")]
+	Application(#[from] EVRApplicationError),
+	#[cfg(feature = "ovr_settings")]
+	#[error("settings error")]
+	Settings(#[from] EVRSettingsError),
+	#[cfg(feature = "image")]
+	#[error("image error")]
+	Image(#[from] SetImageError),
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn overlay_error_display() {
+		let err = EVROverlayError::new(sys::EVROverlayError::VROverlayError_KeyInUse)
+			.unwrap_err();
+		assert_eq!(err.to_string(), "EVROverlayError(17): KeyInUse");
+	}
+
+	#[test]
+	fn display_prints_full_error_code() {
+		let err =
+			EVRInitError::new(sys::EVRInitError::VRInitError_IPC_ServerInitFailed)
+				.unwrap_err();
+		let display = err.to_string();
+		assert!(display.starts_with("EVRInitError(300): "), "{display}");
+	}
+
+	#[test]
+	fn unified_error_chains_source() {
+		let overlay_err =
+			EVROverlayError::new(sys::EVROverlayError::VROverlayError_InvalidHandle)
+				.unwrap_err();
+		let err: Error = overlay_err.into();
+		let source = std::error::Error::source(&err)
+			.and_then(|s| s.downcast_ref::<EVROverlayError>());
+		assert_eq!(source, Some(&overlay_err));
+	}
+}
@@ -0,0 +1,81 @@
+use crate::sys;
+
+/// Layout-compatible mirror of `vr::VREvent_t`.
+///
+/// The event data is a C union, which autocxx can't generate as a POD type, so it
+/// is kept as raw words here and decoded by the typed event wrappers.
+#[derive(Clone, Copy)]
+#[repr(C)]
+// openvr.h packs `VREvent_t` to 4 bytes on these platforms, and to 8 elsewhere.
+#[cfg_attr(any(target_os = "linux", target_os = "macos"), repr(packed(4)))]
+pub(crate) struct RawEvent {
+	pub event_type: u32,
+	pub tracked_device_index: sys::TrackedDeviceIndex_t,
+	pub event_age_seconds: f32,
+	/// `VREvent_Data_t`, whose largest member is six `uint64_t`s.
+	pub data: [u64; 6],
+}
+const _: () = assert!(
+	std::mem::size_of::<RawEvent>()
+		== if cfg!(any(target_os = "linux", target_os = "macos")) {
+			60
+		} else {
+			64
+		}
+);
+
+impl RawEvent {
+	/// Size of the event, as openvr expects it to be passed alongside the pointer.
+	pub const SIZE: u32 = std::mem::size_of::<Self>() as u32;
+
+	pub fn zeroed() -> Self {
+		Self {
+			event_type: 0,
+			tracked_device_index: 0,
+			event_age_seconds: 0.0,
+			data: [0; 6],
+		}
+	}
+
+	/// Reads the `index`-th `uint64_t` of the event data.
+	pub fn data_u64(&self, index: usize) -> u64 {
+		let data = self.data;
+		data[index]
+	}
+
+	/// Reads the `index`-th `float` of the event data.
+	pub fn data_f32(&self, index: usize) -> f32 {
+		f32::from_bits(self.data_u32(index))
+	}
+
+	/// Reads the `index`-th `uint32_t` of the event data.
+	pub fn data_u32(&self, index: usize) -> u32 {
+		// Copy out of the (possibly packed) struct before borrowing.
+		let data = self.data;
+		let word = data[index / 2].to_ne_bytes();
+		let offset = (index % 2) * 4;
+		u32::from_ne_bytes([
+			word[offset],
+			word[offset + 1],
+			word[offset + 2],
+			word[offset + 3],
+		])
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn data_u32_reads_in_memory_order() {
+		let mut word = [0u8; 8];
+		word[..4].copy_from_slice(&7u32.to_ne_bytes());
+		word[4..].copy_from_slice(&9u32.to_ne_bytes());
+		let mut raw = RawEvent::zeroed();
+		raw.data[1] = u64::from_ne_bytes(word);
+
+		assert_eq!(raw.data_u32(2), 7);
+		assert_eq!(raw.data_u32(3), 9);
+	}
+}
@@ -12,6 +12,8 @@ use std::time::Duration;
 pub struct InputManager<'c> {
 	ctx: PhantomData<&'c Context>,
 	inner: Pin<&'c mut sys::IVRInput>,
+	/// Source handles of the left and right hand, looked up on first use.
+	hand_sources: [Option<InputValueHandle>; 2],
 }
 
 #[derive(From, Into, Debug, PartialEq, Eq, Clone, Copy)]
@@ -26,23 +28,208 @@ pub struct ActionHandle(sys::VRActionHandle_t);
 #[repr(transparent)]
 pub struct InputValueHandle(sys::VRInputValueHandle_t);
 
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Hand {
+	Left,
+	Right,
+}
+impl Hand {
+	/// The input source path of this hand, such as `/user/hand/left`.
+	pub fn path(self) -> &'static str {
+		match self {
+			Hand::Left => "/user/hand/left",
+			Hand::Right => "/user/hand/right",
+		}
+	}
+}
+
+/// The input source that an action query is restricted to. Accepted as
+/// `impl Into<Restrict>`, so either a [`Hand`] or an [`InputValueHandle`] can be
+/// passed directly.
+#[derive(From, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Restrict {
+	/// Restrict to the given hand, see [`InputManager::hand_source()`].
+	Hand(Hand),
+	/// Restrict to an arbitrary input source.
+	Source(InputValueHandle),
+}
+
 #[derive(From, Into /*, Debug, PartialEq, Eq, Clone, Copy*/)]
 #[repr(transparent)]
 // TODO: do we want to do something else to forward fields to the sys struct?
 pub struct ActiveActionSet(pub sys::VRActiveActionSet_t);
+impl ActiveActionSet {
+	/// Lowest priority at which an overlay's action set takes precedence over the
+	/// bindings of the scene application.
+	pub const OVERLAY_PRIORITY_MIN: i32 = sys::k_nActionSetOverlayGlobalPriorityMin;
+	/// Highest priority usable by an overlay's action set.
+	pub const OVERLAY_PRIORITY_MAX: i32 = sys::k_nActionSetOverlayGlobalPriorityMax;
+	/// Priorities at or above this are reserved by OpenVR, and using them results in
+	/// `VRInputError_InvalidPriority`.
+	pub const RESERVED_PRIORITY_MIN: i32 = sys::k_nActionSetPriorityReservedMin;
+
+	pub fn priority(&self) -> i32 {
+		self.0.nPriority
+	}
+
+	/// Sets the priority of this action set. Higher priorities take precedence when
+	/// multiple active action sets bind the same input. Overlays that need their
+	/// bindings to win over the scene application's should use a priority between
+	/// [`Self::OVERLAY_PRIORITY_MIN`] and [`Self::OVERLAY_PRIORITY_MAX`].
+	pub fn set_priority(&mut self, priority: i32) {
+		self.0.nPriority = priority;
+	}
+}
 
 #[derive(From, Into /*, Debug, PartialEq, Eq, Clone, Copy*/)]
 #[repr(transparent)]
 pub struct DigitalActionData(pub sys::InputDigitalActionData_t);
+impl DigitalActionData {
+	/// Whether the action is bound and its action set is active.
+	pub fn active(&self) -> bool {
+		self.0.bActive
+	}
+
+	/// The origin that caused the current state.
+	pub fn active_origin(&self) -> InputValueHandle {
+		InputValueHandle(self.0.activeOrigin)
+	}
+
+	/// Whether the button is currently pressed.
+	pub fn state(&self) -> bool {
+		self.0.bState
+	}
+
+	/// Whether [`Self::state()`] changed since the previous call to
+	/// `update_actions()`.
+	pub fn changed(&self) -> bool {
+		self.0.bChanged
+	}
+
+	/// When the state changed, in seconds relative to now. Usually negative.
+	pub fn update_time(&self) -> f32 {
+		self.0.fUpdateTime
+	}
+
+	/// Combines [`Self::state()`] and [`Self::changed()`] into a single edge. An
+	/// inactive action is always [`ActionEdge::Idle`].
+	pub fn edge(&self) -> ActionEdge {
+		match (self.active() && self.state(), self.changed()) {
+			(true, true) => ActionEdge::Pressed,
+			(true, false) => ActionEdge::Held,
+			// Deactivating the action set while held also reports a release.
+			(false, true) => ActionEdge::Released,
+			(false, false) => ActionEdge::Idle,
+		}
+	}
+}
+
+/// How a digital action changed since the previous `update_actions()`, see
+/// [`DigitalActionData::edge()`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ActionEdge {
+	/// Pressed since the previous update.
+	Pressed,
+	/// Released since the previous update.
+	Released,
+	/// Pressed, and already was on the previous update.
+	Held,
+	/// Not pressed, and wasn't on the previous update either.
+	Idle,
+}
+
+#[derive(From, Into /*, Debug, PartialEq, Eq, Clone, Copy*/)]
+#[repr(transparent)]
+pub struct AnalogActionData(pub sys::InputAnalogActionData_t);
+impl AnalogActionData {
+	/// Whether the action is bound and its action set is active. If not, the other
+	/// values are all zero.
+	pub fn active(&self) -> bool {
+		self.0.bActive
+	}
+
+	pub fn x(&self) -> f32 {
+		self.0.x
+	}
+
+	pub fn y(&self) -> f32 {
+		self.0.y
+	}
+
+	pub fn z(&self) -> f32 {
+		self.0.z
+	}
+
+	/// Change in [`Self::x()`] since the previous call to `update_actions()`.
+	pub fn delta_x(&self) -> f32 {
+		self.0.deltaX
+	}
+
+	/// Change in [`Self::y()`] since the previous call to `update_actions()`.
+	pub fn delta_y(&self) -> f32 {
+		self.0.deltaY
+	}
+
+	/// Change in [`Self::z()`] since the previous call to `update_actions()`.
+	pub fn delta_z(&self) -> f32 {
+		self.0.deltaZ
+	}
+}
 
 #[derive(From, Into /*, Debug, PartialEq, Eq, Clone, Copy*/)]
 #[repr(transparent)]
 pub struct PoseActionData(pub sys::InputPoseActionData_t);
+impl PoseActionData {
+	/// Whether the action is bound and its action set is active.
+	pub fn active(&self) -> bool {
+		self.0.bActive
+	}
+
+	/// The origin that provided the pose.
+	pub fn active_origin(&self) -> InputValueHandle {
+		InputValueHandle(self.0.activeOrigin)
+	}
+
+	pub fn pose(&self) -> &pose::TrackedDevicePose {
+		let pose = &self.0.pose as *const sys::TrackedDevicePose_t;
+		// safety: `TrackedDevicePose` is a `repr(transparent)` wrapper of the sys type
+		unsafe { &*pose.cast() }
+	}
+}
 
 #[derive(From, Into /*, Debug, PartialEq, Eq, Clone, Copy*/)]
 #[repr(transparent)]
 pub struct OriginInfo(pub sys::InputOriginInfo_t);
 
+/// The transform of a single bone, relative to the space requested in
+/// [`InputManager::get_skeletal_bone_data()`].
+#[derive(From, Into)]
+#[repr(transparent)]
+pub struct BoneTransform(pub sys::VRBoneTransform_t);
+impl BoneTransform {
+	/// The position as `[x, y, z, w]`, where `w` is always 1.
+	pub fn position(&self) -> [f32; 4] {
+		self.0.position.v
+	}
+
+	/// The orientation quaternion as `[w, x, y, z]`.
+	pub fn orientation(&self) -> [f32; 4] {
+		let q = &self.0.orientation;
+		[q.w, q.x, q.y, q.z]
+	}
+}
+
+/// Summary of the shape of a hand, see [`InputManager::get_skeletal_summary_data()`].
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct SkeletalSummaryData {
+	/// How far each finger is curled, from thumb to pinky. 0 is fully extended and
+	/// 1 is fully curled.
+	pub finger_curl: [f32; 5],
+	/// How far apart each pair of adjacent fingers is, from thumb-index to
+	/// ring-pinky. 0 is together and 1 is fully spread.
+	pub finger_splay: [f32; 4],
+}
+
 type Result<T> = std::result::Result<T, EVRInputError>;
 
 pub trait ToSeconds {
@@ -67,6 +254,19 @@ impl ToSeconds for &Duration {
 	}
 }
 
+/// Parameters of a haptic vibration, for [`InputManager::trigger_haptic()`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HapticParams {
+	/// Delay before the vibration starts.
+	pub start: Duration,
+	/// How long the vibration lasts.
+	pub duration: Duration,
+	/// Frequency of the vibration, in Hz.
+	pub frequency_hz: f32,
+	/// Strength of the vibration, in `[0,1]`.
+	pub amplitude: f32,
+}
+
 #[derive(EnumSetType, Debug)]
 #[enumset(repr = "u32")]
 pub enum InputString {
@@ -84,6 +284,7 @@ impl<'c> InputManager<'c> {
 		Self {
 			ctx: Default::default(),
 			inner,
+			hand_sources: [None; 2],
 		}
 	}
 
@@ -180,6 +381,28 @@ impl<'c> InputManager<'c> {
 		Ok(InputValueHandle(handle))
 	}
 
+	/// Gets the source handle of `hand`. The handle is only looked up once per
+	/// manager.
+	pub fn hand_source(&mut self, hand: Hand) -> Result<InputValueHandle> {
+		let slot = hand as usize;
+		if let Some(handle) = self.hand_sources[slot] {
+			return Ok(handle);
+		}
+		let handle = self.get_input_source_handle(hand.path())?;
+		self.hand_sources[slot] = Some(handle);
+		Ok(handle)
+	}
+
+	fn resolve_restrict(
+		&mut self,
+		restrict: impl Into<Restrict>,
+	) -> Result<InputValueHandle> {
+		match restrict.into() {
+			Restrict::Hand(hand) => self.hand_source(hand),
+			Restrict::Source(handle) => Ok(handle),
+		}
+	}
+
 	// ---- Read Action State ----
 
 	pub fn update_actions(&mut self, sets: &mut [ActiveActionSet]) -> Result<()> {
@@ -199,8 +422,9 @@ impl<'c> InputManager<'c> {
 	pub fn get_digital_action_data(
 		&mut self,
 		action: ActionHandle,
-		restrict: InputValueHandle,
+		restrict: impl Into<Restrict>,
 	) -> Result<DigitalActionData> {
+		let restrict = self.resolve_restrict(restrict)?;
 		let mut data: MaybeUninit<sys::InputDigitalActionData_t> =
 			MaybeUninit::uninit();
 		let err = unsafe {
@@ -215,13 +439,77 @@ impl<'c> InputManager<'c> {
 		Ok(DigitalActionData(unsafe { data.assume_init() }))
 	}
 
+	/// Same as [`Self::get_digital_action_data()`], but only returns how the action
+	/// changed since the previous `update_actions()`.
+	pub fn digital_action_edges(
+		&mut self,
+		action: ActionHandle,
+		restrict: impl Into<Restrict>,
+	) -> Result<ActionEdge> {
+		Ok(self.get_digital_action_data(action, restrict)?.edge())
+	}
+
+	/// Whether a controller that `action` is bound to was connected and active
+	/// during the last `update_actions()`, such as to show a "turn on your
+	/// controllers" prompt otherwise. `restrict` picks the controller to check,
+	/// usually a [`Hand`].
+	///
+	/// `action` should be a digital action that is bound on every supported
+	/// controller. Errors count as inactive.
+	pub fn is_any_controller_active(
+		&mut self,
+		action: ActionHandle,
+		restrict: impl Into<Restrict>,
+	) -> bool {
+		self.get_digital_action_data(action, restrict)
+			.map_or(false, |data| data.active())
+	}
+
+	pub fn get_analog_action_data(
+		&mut self,
+		action: ActionHandle,
+		restrict: impl Into<Restrict>,
+	) -> Result<AnalogActionData> {
+		let restrict = self.resolve_restrict(restrict)?;
+		let mut data: MaybeUninit<sys::InputAnalogActionData_t> = MaybeUninit::uninit();
+		let err = unsafe {
+			self.inner.as_mut().GetAnalogActionData(
+				action.0,
+				data.as_mut_ptr(),
+				std::mem::size_of::<sys::InputAnalogActionData_t>() as u32,
+				restrict.0,
+			)
+		};
+		EVRInputError::new(err)?;
+		Ok(AnalogActionData(unsafe { data.assume_init() }))
+	}
+
+	/// Gets the analog action data of each action in `actions`, all restricted to
+	/// the same `restrict` device. Results are in the same order as `actions`, and
+	/// a failure for one action does not prevent reading the others.
+	pub fn get_analog_action_data_batch(
+		&mut self,
+		actions: &[ActionHandle],
+		restrict: impl Into<Restrict>,
+	) -> Vec<Result<AnalogActionData>> {
+		let restrict = match self.resolve_restrict(restrict) {
+			Ok(restrict) => restrict,
+			Err(err) => return actions.iter().map(|_| Err(err)).collect(),
+		};
+		actions
+			.iter()
+			.map(|&action| self.get_analog_action_data(action, restrict))
+			.collect()
+	}
+
 	pub fn get_pose_action_data_relative_to_now(
 		&mut self,
 		action: ActionHandle,
 		universe: pose::TrackingUniverseOrigin,
 		seconds_from_now: impl ToSeconds,
-		restrict: InputValueHandle,
+		restrict: impl Into<Restrict>,
 	) -> Result<PoseActionData> {
+		let restrict = self.resolve_restrict(restrict)?;
 		let mut data: MaybeUninit<sys::InputPoseActionData_t> = MaybeUninit::uninit();
 		let err = unsafe {
 			self.inner.as_mut().GetPoseActionDataRelativeToNow(
@@ -238,30 +526,280 @@ impl<'c> InputManager<'c> {
 		Ok(PoseActionData(unsafe { data.assume_init() }))
 	}
 
+	/// Gets the pose data of several actions at once, each restricted to its own
+	/// input source, such as all trackers of a full body tracking setup.
+	///
+	/// OpenVR has no bulk call for this, so this still makes one call per action.
+	/// Each action gets its own result, so one failing action doesn't prevent the
+	/// others from being read.
+	pub fn get_pose_action_data_batch(
+		&mut self,
+		actions: &[(ActionHandle, InputValueHandle)],
+		universe: pose::TrackingUniverseOrigin,
+		seconds_from_now: impl ToSeconds,
+	) -> Vec<Result<PoseActionData>> {
+		let seconds_from_now = seconds_from_now.to_seconds();
+		actions
+			.iter()
+			.map(|&(action, restrict)| {
+				self.get_pose_action_data_relative_to_now(
+					action,
+					universe,
+					seconds_from_now,
+					restrict,
+				)
+			})
+			.collect()
+	}
+
+	/// Gets the device-to-universe transform of a pose action, or `None` if the
+	/// action is inactive or its pose isn't valid.
+	///
+	/// Shorthand for [`Self::get_pose_action_data_relative_to_now()`].
+	pub fn get_pose_matrix(
+		&mut self,
+		action: ActionHandle,
+		universe: pose::TrackingUniverseOrigin,
+		seconds_from_now: impl ToSeconds,
+		restrict: impl Into<Restrict>,
+	) -> Result<Option<pose::Matrix3x4>> {
+		let data = self.get_pose_action_data_relative_to_now(
+			action,
+			universe,
+			seconds_from_now,
+			restrict,
+		)?;
+		let data = data.0;
+		if !data.bActive || !data.pose.bPoseIsValid {
+			return Ok(None);
+		}
+		Ok(Some(
+			pose::TrackedDevicePose(data.pose).device_to_absolute_tracking(),
+		))
+	}
+
+	// ---- Haptics ----
+
+	/// Vibrates the device bound to a vibration action, starting
+	/// `start_seconds_from_now` and lasting for `duration`. `frequency` is in Hz,
+	/// and `amplitude` ranges from 0.0 (off) to 1.0 (strongest).
+	///
+	/// Returns `VRInputError_WrongType` if `action` is not a vibration action.
+	///
+	/// Wraps c++ `TriggerHapticVibrationAction`.
+	pub fn trigger_haptic_vibration(
+		&mut self,
+		action: ActionHandle,
+		start_seconds_from_now: impl ToSeconds,
+		duration: impl ToSeconds,
+		frequency: f32,
+		amplitude: f32,
+		restrict: impl Into<Restrict>,
+	) -> Result<()> {
+		let restrict = self.resolve_restrict(restrict)?;
+		let err = unsafe {
+			self.inner.as_mut().TriggerHapticVibrationAction(
+				action.0,
+				start_seconds_from_now.to_seconds(),
+				duration.to_seconds(),
+				frequency,
+				amplitude,
+				restrict.0,
+			)
+		};
+		EVRInputError::new(err)
+	}
+
+	/// Same as [`Self::trigger_haptic_vibration()`], but with named parameters so
+	/// that the start delay and duration can't be mixed up.
+	pub fn trigger_haptic(
+		&mut self,
+		action: ActionHandle,
+		params: HapticParams,
+		restrict: impl Into<Restrict>,
+	) -> Result<()> {
+		self.trigger_haptic_vibration(
+			action,
+			&params.start,
+			&params.duration,
+			params.frequency_hz,
+			params.amplitude,
+			restrict,
+		)
+	}
+
+	// ---- Skeletal Input ----
+
+	/// Gets the number of bones in the skeleton bound to a skeleton action.
+	pub fn get_bone_count(&mut self, action: ActionHandle) -> Result<u32> {
+		let mut count = 0;
+		let err = unsafe { self.inner.as_mut().GetBoneCount(action.0, &mut count) };
+		EVRInputError::new(err)?;
+		Ok(count)
+	}
+
+	/// Gets the transform of every bone of the skeleton bound to `action`.
+	///
+	/// Returns `VRInputError_InvalidSkeleton` if `action` has no skeleton bound,
+	/// for example because the controller doesn't support skeletal input.
+	pub fn get_skeletal_bone_data(
+		&mut self,
+		action: ActionHandle,
+		transform_space: sys::EVRSkeletalTransformSpace,
+		motion_range: sys::EVRSkeletalMotionRange,
+	) -> Result<Vec<BoneTransform>> {
+		// Enough for the bound skeleton to change while querying, but doesn't spin
+		// forever if openvr keeps complaining.
+		const ATTEMPTS: usize = 3;
+
+		for _ in 0..ATTEMPTS {
+			let count = self.get_bone_count(action)?;
+			let mut bones: Vec<MaybeUninit<sys::VRBoneTransform_t>> =
+				(0..count).map(|_| MaybeUninit::uninit()).collect();
+			let err = unsafe {
+				self.inner.as_mut().GetSkeletalBoneData(
+					action.0,
+					transform_space,
+					motion_range,
+					bones.as_mut_ptr().cast(),
+					count,
+				)
+			};
+			// The bound skeleton changed in between, so query the new bone count.
+			if err == sys::EVRInputError::VRInputError_BufferTooSmall {
+				continue;
+			}
+			EVRInputError::new(err)?;
+			return Ok(bones
+				.into_iter()
+				.map(|bone| BoneTransform(unsafe { bone.assume_init() }))
+				.collect());
+		}
+		Err(sys::EVRInputError::VRInputError_BufferTooSmall.into())
+	}
+
+	/// Gets the curl and splay of each finger of the skeleton bound to `action`.
+	///
+	/// This is much cheaper than [`Self::get_skeletal_bone_data()`] when only a
+	/// rough idea of the hand's shape is needed.
+	pub fn get_skeletal_summary_data(
+		&mut self,
+		action: ActionHandle,
+		summary_type: sys::EVRSummaryType,
+	) -> Result<SkeletalSummaryData> {
+		let mut data: MaybeUninit<sys::VRSkeletalSummaryData_t> = MaybeUninit::uninit();
+		let err = unsafe {
+			self.inner.as_mut().GetSkeletalSummaryData(
+				action.0,
+				summary_type,
+				data.as_mut_ptr(),
+			)
+		};
+		EVRInputError::new(err)?;
+		let data = unsafe { data.assume_init() };
+		Ok(SkeletalSummaryData {
+			finger_curl: data.flFingerCurl,
+			finger_splay: data.flFingerSplay,
+		})
+	}
+
 	// ---- Action Origins ----
 
+	/// Gets the origins, such as a button or trackpad, that `action` is currently
+	/// bound to.
+	///
+	/// Use [`Self::get_origin_localized_name()`] to turn each origin into a string
+	/// that can be shown to the user.
+	pub fn get_action_origins(
+		&mut self,
+		action_set: ActionSetHandle,
+		action: ActionHandle,
+	) -> Result<Vec<InputValueHandle>> {
+		let mut origins: Vec<sys::VRInputValueHandle_t> =
+			vec![0; sys::k_unMaxActionOriginCount as usize];
+		let err = unsafe {
+			self.inner.as_mut().GetActionOrigins(
+				action_set.0,
+				action.0,
+				origins.as_mut_ptr(),
+				origins.len() as u32,
+			)
+		};
+		EVRInputError::new(err)?;
+		// Unused slots are left as `k_ulInvalidInputValueHandle`, which is 0.
+		let len = origins.iter().rposition(|&h| h != 0).map_or(0, |i| i + 1);
+		origins.truncate(len);
+		Ok(origins.into_iter().map(InputValueHandle).collect())
+	}
+
 	pub fn get_origin_localized_name(
 		&mut self,
 		origin: InputValueHandle,
 		bits: EnumSet<InputString>,
 	) -> Result<String> {
-		let mut name = vec![0u8; 100];
-		let err = unsafe {
-			self.inner.as_mut().GetOriginLocalizedName(
-				origin.0,
-				name.as_mut_ptr() as *mut i8,
-				name.len() as u32 - 1, // TODO: is there *actually* an off-by-one here?
-				bits.as_repr() as i32,
-			)
+		self.get_origin_localized_name_with_capacity(origin, bits, 100)
+	}
+
+	/// Gets a human-readable name for `action`, from the first origin it is bound
+	/// to. Returns `None` if the action isn't bound to anything.
+	///
+	/// OpenVR has no way to name actions or action sets directly, so this names
+	/// the control instead, such as "Left Hand Trigger" depending on `bits`.
+	///
+	/// There is deliberately no equivalent for an [`ActionSetHandle`] on its own:
+	/// an action set has no origin to name, and its localized name only exists in
+	/// the action manifest, which OpenVR doesn't expose. Read it from your manifest
+	/// instead.
+	pub fn get_action_localized_name(
+		&mut self,
+		action_set: ActionSetHandle,
+		action: ActionHandle,
+		bits: EnumSet<InputString>,
+	) -> Result<Option<String>> {
+		let Some(&origin) = self.get_action_origins(action_set, action)?.first() else {
+			return Ok(None);
 		};
+		self.get_origin_localized_name(origin, bits).map(Some)
+	}
 
-		EVRInputError::new(err)?;
-		Ok(CString::from_vec_with_nul(name)
-			.expect("There should be a null byte left!")
-			// This shouldn't copy
-			.into_string()
-			// This path should only copy once, and only if there is invalid utf8. wish there was an `into_string_lossy`.
-			.unwrap_or_else(|err| err.into_cstring().to_string_lossy().into_owned()))
+	/// Same as [`Self::get_origin_localized_name()`], but starts out with a buffer
+	/// of `capacity` bytes. If the name doesn't fit, the buffer is grown until it
+	/// does.
+	pub fn get_origin_localized_name_with_capacity(
+		&mut self,
+		origin: InputValueHandle,
+		bits: EnumSet<InputString>,
+		capacity: usize,
+	) -> Result<String> {
+		// Upper bound on buffer growth, in case openvr never stops complaining.
+		const MAX_CAPACITY: usize = 1 << 16;
+
+		let mut name = vec![0u8; capacity.max(1)];
+		loop {
+			let err = unsafe {
+				self.inner.as_mut().GetOriginLocalizedName(
+					origin.0,
+					name.as_mut_ptr() as *mut i8,
+					name.len() as u32,
+					bits.as_repr() as i32,
+				)
+			};
+			let too_small = err == sys::EVRInputError::VRInputError_BufferTooSmall
+				// Also grow if openvr filled the buffer without nul-terminating it.
+				|| (err == sys::EVRInputError::VRInputError_None && !name.contains(&0));
+			if too_small && name.len() < MAX_CAPACITY {
+				let new_len = (name.len() * 2).min(MAX_CAPACITY);
+				name.resize(new_len, 0);
+				continue;
+			}
+			EVRInputError::new(err)?;
+			break;
+		}
+
+		let name = CStr::from_bytes_until_nul(&name).map_err(|_| {
+			EVRInputError::from(sys::EVRInputError::VRInputError_BufferTooSmall)
+		})?;
+		Ok(name.to_string_lossy().into_owned())
 	}
 
 	pub fn get_origin_tracked_device_info(
@@ -280,4 +818,68 @@ impl<'c> InputManager<'c> {
 		EVRInputError::new(err)?;
 		Ok(OriginInfo(unsafe { data.assume_init() }))
 	}
+
+	// ---- Binding UI ----
+
+	/// Opens the SteamVR binding UI, so the user can configure their controls.
+	///
+	/// `app_key` of `None` means the calling application. If `action_set` is
+	/// given, the UI opens on that action set. `device` selects the controller to
+	/// show the bindings of, and `show_on_desktop` shows the UI on the desktop
+	/// instead of in the headset.
+	///
+	/// This only works while SteamVR is running, and after the action manifest has
+	/// been loaded with [`Self::set_action_manifest()`].
+	pub fn open_binding_ui(
+		&mut self,
+		app_key: Option<&str>,
+		action_set: Option<ActionSetHandle>,
+		device: InputValueHandle,
+		show_on_desktop: bool,
+	) -> Result<()> {
+		let app_key = match app_key.map(CString::new) {
+			Some(Ok(s)) => Some(s),
+			Some(Err(_)) => {
+				return EVRInputError::new(
+					sys::EVRInputError::VRInputError_InvalidParam,
+				)
+			}
+			None => None,
+		};
+		let app_key = app_key.as_ref().map_or(std::ptr::null(), |s| s.as_ptr());
+		let err = unsafe {
+			self.inner.as_mut().OpenBindingUI(
+				app_key,
+				action_set.map_or(0, |s| s.0),
+				device.0,
+				show_on_desktop,
+			)
+		};
+		EVRInputError::new(err)
+	}
+}
+unsafe impl Send for InputManager<'_> {}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn digital(active: bool, state: bool, changed: bool) -> DigitalActionData {
+		DigitalActionData(sys::InputDigitalActionData_t {
+			bActive: active,
+			activeOrigin: 0,
+			bState: state,
+			bChanged: changed,
+			fUpdateTime: 0.,
+		})
+	}
+
+	#[test]
+	fn digital_action_edges() {
+		assert_eq!(digital(true, true, true).edge(), ActionEdge::Pressed);
+		assert_eq!(digital(true, false, true).edge(), ActionEdge::Released);
+		assert_eq!(digital(true, true, false).edge(), ActionEdge::Held);
+		assert_eq!(digital(true, false, false).edge(), ActionEdge::Idle);
+		assert_eq!(digital(false, true, false).edge(), ActionEdge::Idle);
+	}
 }
@@ -1,6 +1,19 @@
 //! Create a [`Context`] to get started.
 //!
 //! **This library makes no semver guarantees until version 0.1.0 or greater.**
+//!
+//! # Threading
+//!
+//! OpenVR doesn't document any of its interface calls as safe to call
+//! concurrently, so this crate treats none of them as such. Only `VR_Init` and
+//! `VR_Shutdown`, through [`Context::init()`] and [`Context::shutdown()`], are
+//! synchronized by this crate.
+//!
+//! The [`Context`] is `Send` but not `Sync`, so managers can only be created on
+//! the thread that currently owns it. Managers are `Send` but not `Sync`: a
+//! manager can be moved to another thread, but not shared between threads. When
+//! moving a manager, don't keep using another manager of the same interface on
+//! the original thread at the same time.
 #![allow(clippy::result_unit_err)]
 
 pub mod overlay;
@@ -8,6 +21,11 @@ use self::overlay::OverlayManager;
 
 pub mod pose;
 
+#[cfg(feature = "ovr_chaperone")]
+pub mod chaperone;
+#[cfg(feature = "ovr_chaperone")]
+use self::chaperone::ChaperoneManager;
+
 #[cfg(feature = "ovr_chaperone_setup")]
 pub mod chaperone_setup;
 #[cfg(feature = "ovr_chaperone_setup")]
@@ -28,15 +46,25 @@ pub mod applications;
 #[cfg(feature = "ovr_applications")]
 use self::applications::ApplicationsManager;
 
+#[cfg(feature = "ovr_settings")]
+pub mod settings;
+#[cfg(feature = "ovr_settings")]
+use self::settings::SettingsManager;
+
 mod errors;
+mod event;
 
-pub use self::errors::{EVRInitError, InitError};
+pub use self::errors::{EVRInitError, Error, InitError};
 pub use ovr_overlay_sys as sys;
 
 use derive_more::{From, Into};
 use lazy_static::lazy_static;
+use std::cell::Cell;
+use std::ffi::{c_void, CStr};
 use std::fmt::Debug;
-use std::sync::Mutex;
+use std::marker::PhantomData;
+use std::path::PathBuf;
+use std::sync::{Mutex, PoisonError};
 
 lazy_static! {
 	// Mutex instead of atomic allows for blocking on lock
@@ -45,43 +73,123 @@ lazy_static! {
 
 /// Represents an active OpenVR context.
 ///
-/// Shutting down this context is unsafe, so if this is dropped, the context will
-/// remain active, as leaking resources is better than accidentally causing unsafe
-/// behavior. To actually shut down, call [`Self::shutdown()`]..
-pub struct Context {}
+/// If this is dropped, the context will remain active. To actually shut down,
+/// call [`Self::shutdown()`]. In debug builds, dropping an active context logs a
+/// warning, as this is usually a mistake.
+pub struct Context {
+	// Only constructible via `init()`, so that a `Context` always means OpenVR is
+	// initialized.
+	_private: (),
+	// Not `Sync`, so that managers can't be created from several threads at once.
+	_not_sync: PhantomData<Cell<()>>,
+}
 impl Context {
+	/// Initializes OpenVR.
+	///
+	/// Returns [`InitError::AlreadyInitialized`] only if OpenVR is actually
+	/// initialized already. If initialization fails, it may be retried.
 	pub fn init() -> Result<Self, InitError> {
-		if let Ok(guard) = INITIALIZED.try_lock() {
-			if *guard {
-				return Err(InitError::AlreadyInitialized);
-			}
-			let mut err = std::mem::MaybeUninit::<sys::EVRInitError>::uninit();
-			let err = unsafe {
-				let _ = sys::VR_Init(
-					err.as_mut_ptr(),
-					sys::EVRApplicationType::VRApplication_Overlay,
-					std::ptr::null(),
-				);
-				err.assume_init()
-			};
-			EVRInitError::new(err)?;
-			Ok(Self {})
-		} else {
-			Err(InitError::AlreadyInitialized)
+		// Block rather than fail if another thread is currently (de)initializing.
+		// The guarded bool is always valid, so it's fine to recover from poisoning.
+		let mut guard = INITIALIZED.lock().unwrap_or_else(PoisonError::into_inner);
+		if *guard {
+			return Err(InitError::AlreadyInitialized);
 		}
+		let mut err = std::mem::MaybeUninit::<sys::EVRInitError>::uninit();
+		let err = unsafe {
+			let _ = sys::VR_Init(
+				err.as_mut_ptr(),
+				sys::EVRApplicationType::VRApplication_Overlay,
+				std::ptr::null(),
+			);
+			err.assume_init()
+		};
+		// Only mark as initialized on success, so that a failed init can be retried.
+		EVRInitError::new(err)?;
+		*guard = true;
+		Ok(Self {
+			_private: (),
+			_not_sync: PhantomData,
+		})
+	}
+
+	/// Shuts down OpenVR.
+	///
+	/// Every manager borrows the context, so the borrow checker guarantees that
+	/// none of them are alive anymore. Afterwards, [`Self::init()`] may be called
+	/// again.
+	pub fn shutdown(self) {
+		unsafe { self.shutdown_unchecked() }
 	}
 
-	// TODO: Is this actually unsafe?
+	/// Shuts down OpenVR without consuming the context, for when it can't be moved
+	/// out of wherever it is stored.
+	///
 	/// # Safety
-	/// see <https://docs.rs/openvr/latest/openvr/struct.Context.html#safety>
-	pub unsafe fn shutdown(self) {
-		sys::VR_Shutdown()
+	/// Neither this context nor any manager obtained from it may be used after
+	/// this call. See <https://docs.rs/openvr/latest/openvr/struct.Context.html#safety>
+	pub unsafe fn shutdown_unchecked(&self) {
+		let mut guard = INITIALIZED.lock().unwrap_or_else(PoisonError::into_inner);
+		// Protects against shutting down twice through this escape hatch.
+		if !*guard {
+			return;
+		}
+		sys::VR_Shutdown();
+		*guard = false;
+	}
+
+	/// Gets a raw pointer to the OpenVR interface `interface_version`, such as
+	/// `IVRSystem_022`.
+	///
+	/// This is an escape hatch for interfaces that this crate doesn't wrap yet.
+	/// Nothing about the returned pointer is checked: it's up to the caller to cast
+	/// it to the right vtable layout for that exact version, to only use it from
+	/// one thread at a time, and to not use it after [`Self::shutdown()`].
+	pub fn get_generic_interface(
+		&self,
+		interface_version: &CStr,
+	) -> Result<*mut c_void, EVRInitError> {
+		let mut err = sys::EVRInitError::VRInitError_None;
+		let interface = unsafe {
+			sys::VR_GetGenericInterface(interface_version.as_ptr(), &mut err)
+		};
+		EVRInitError::new(err)?;
+		Ok(interface.cast())
+	}
+
+	/// Gets the directory of the OpenVR runtime that is or would be used, which is
+	/// useful for diagnosing when the wrong runtime is picked up. Doesn't require
+	/// OpenVR to be initialized.
+	///
+	/// Returns `None` if there is no runtime installed, or its path is not UTF-8.
+	///
+	/// Wraps c++ `VR_GetRuntimePath`.
+	pub fn runtime_path() -> Option<PathBuf> {
+		let mut len = 0;
+		// The first call only asks for the required length, including the nul.
+		unsafe { sys::VR_GetRuntimePath(std::ptr::null_mut(), 0, &mut len) };
+		if len == 0 {
+			return None;
+		}
+		let mut buf = vec![0u8; len as usize];
+		let ok =
+			unsafe { sys::VR_GetRuntimePath(buf.as_mut_ptr().cast(), len, &mut len) };
+		if !ok {
+			return None;
+		}
+		let path = CStr::from_bytes_until_nul(&buf).ok()?;
+		Some(PathBuf::from(path.to_str().ok()?))
 	}
 
 	pub fn overlay_mngr(&self) -> OverlayManager<'_> {
 		OverlayManager::new(self)
 	}
 
+	#[cfg(feature = "ovr_chaperone")]
+	pub fn chaperone_mngr(&self) -> ChaperoneManager<'_> {
+		ChaperoneManager::new(self)
+	}
+
 	#[cfg(feature = "ovr_chaperone_setup")]
 	pub fn chaperone_setup_mngr(&self) -> ChaperoneSetupManager<'_> {
 		ChaperoneSetupManager::new(self)
@@ -101,16 +209,71 @@ impl Context {
 	pub fn applications_mngr(&self) -> ApplicationsManager<'_> {
 		ApplicationsManager::new(self)
 	}
+
+	#[cfg(feature = "ovr_settings")]
+	pub fn settings_mngr(&self) -> SettingsManager<'_> {
+		SettingsManager::new(self)
+	}
+}
+impl Drop for Context {
+	fn drop(&mut self) {
+		// OpenVR is deliberately left initialized, but make the leak visible.
+		if cfg!(debug_assertions)
+			&& *INITIALIZED.lock().unwrap_or_else(PoisonError::into_inner)
+		{
+			log::warn!(
+				"`Context` was dropped without calling `shutdown()`, OpenVR remains initialized"
+			);
+		}
+	}
 }
 
 /// Tints each color channel by multiplying it with the given f32
+///
+/// Color channels may go above 1.0 to brighten an HDR overlay, for example to
+/// trigger bloom. Alpha is an opacity and only meaningful in `[0,1]`, see
+/// [`Self::clamp_alpha()`].
+///
+/// Prefer [`Self::new()`] or [`Self::from_rgba8()`] over setting the fields
+/// directly, as OpenVR handles negative or non-finite values unpredictably.
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ColorTint {
 	pub r: f32,
 	pub g: f32,
 	pub b: f32,
 	pub a: f32,
 }
+impl ColorTint {
+	/// Returns `Err(())` if any channel is negative or not finite.
+	pub fn new(r: f32, g: f32, b: f32, a: f32) -> Result<Self, ()> {
+		if [r, g, b, a].iter().all(|c| c.is_finite() && *c >= 0.0) {
+			Ok(Self { r, g, b, a })
+		} else {
+			Err(())
+		}
+	}
+
+	/// Returns this tint with alpha clamped to `[0,1]`, leaving the color channels
+	/// untouched.
+	pub fn clamp_alpha(self) -> Self {
+		Self {
+			a: self.a.clamp(0.0, 1.0),
+			..self
+		}
+	}
+
+	/// Creates a tint from 0-255 channels, with 255 leaving the channel unchanged.
+	pub fn from_rgba8(r: u8, g: u8, b: u8, a: u8) -> Self {
+		let c = |c: u8| f32::from(c) / 255.0;
+		Self {
+			r: c(r),
+			g: c(g),
+			b: c(b),
+			a: c(a),
+		}
+	}
+}
 impl Default for ColorTint {
 	fn default() -> Self {
 		Self {
@@ -122,8 +285,49 @@ impl Default for ColorTint {
 	}
 }
 
+/// The region of a texture that an overlay shows, in texture coordinates.
+///
+/// Values outside of `[0,1]` are passed to OpenVR as-is, and either repeat or
+/// clamp the texture depending on how its sampler is set up.
 #[derive(From, Into)]
 pub struct TextureBounds(pub sys::VRTextureBounds_t);
+impl TextureBounds {
+	pub fn new(u_min: f32, v_min: f32, u_max: f32, v_max: f32) -> Self {
+		Self(sys::VRTextureBounds_t {
+			uMin: u_min,
+			vMin: v_min,
+			uMax: u_max,
+			vMax: v_max,
+		})
+	}
+
+	/// Bounds covering the whole texture, which is what OpenVR uses by default.
+	pub fn full() -> Self {
+		Self::new(0.0, 0.0, 1.0, 1.0)
+	}
+
+	/// Flips the bounds upside down, by swapping `vMin` and `vMax`. Useful when
+	/// the texture origin is at the bottom, as in OpenGL.
+	#[must_use]
+	pub fn flip_vertical(mut self) -> Self {
+		std::mem::swap(&mut self.0.vMin, &mut self.0.vMax);
+		self
+	}
+
+	/// Mirrors the bounds left to right, by swapping `uMin` and `uMax`.
+	#[must_use]
+	pub fn flip_horizontal(mut self) -> Self {
+		std::mem::swap(&mut self.0.uMin, &mut self.0.uMax);
+		self
+	}
+
+	/// Whether all four values are finite, which OpenVR requires.
+	pub fn is_finite(&self) -> bool {
+		[self.0.uMin, self.0.vMin, self.0.uMax, self.0.vMax]
+			.iter()
+			.all(|v| v.is_finite())
+	}
+}
 impl Clone for TextureBounds {
 	fn clone(&self) -> Self {
 		Self(sys::VRTextureBounds_t {
@@ -145,7 +349,7 @@ impl Debug for TextureBounds {
 	}
 }
 
-#[derive(Clone, Copy, From, Into)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, From, Into)]
 #[repr(transparent)]
 pub struct TrackedDeviceIndex(pub sys::TrackedDeviceIndex_t);
 impl TrackedDeviceIndex {
@@ -164,6 +368,12 @@ impl TrackedDeviceIndex {
 	/// Maximum number of Tracked Devices
 	pub const MAX: usize = sys::k_unMaxTrackedDeviceCount as usize;
 
+	/// Iterates over every possible device index, from [`Self::HMD`] up to
+	/// [`Self::MAX`]. Most of them usually have no device connected.
+	pub fn all() -> impl Iterator<Item = TrackedDeviceIndex> {
+		(0..sys::k_unMaxTrackedDeviceCount).map(Self)
+	}
+
 	// Please open an issue on the github repository if you need this.
 	// pub const fn is_other(&self) -> bool {
 	//     self.0 == sys::k_unTrackedDeviceIndexOther
@@ -172,14 +382,194 @@ impl TrackedDeviceIndex {
 
 #[cfg(test)]
 mod tests {
+	use super::*;
+
+	/// Held by every test that initializes OpenVR. Only one [`Context`] can exist at
+	/// a time, but tests run in parallel.
+	static RUNTIME_LOCK: Mutex<()> = Mutex::new(());
+
+	pub(crate) fn lock_runtime() -> std::sync::MutexGuard<'static, ()> {
+		// A failed test poisons the lock, which mustn't fail the other tests.
+		RUNTIME_LOCK.lock().unwrap_or_else(PoisonError::into_inner)
+	}
+
+	/// Runs `f` with a fresh [`Context`], one test at a time. The context is shut
+	/// down afterwards, even if `f` panics.
+	pub(crate) fn with_context(f: impl FnOnce(&Context)) {
+		struct ShutdownOnDrop(Context);
+		impl Drop for ShutdownOnDrop {
+			fn drop(&mut self) {
+				unsafe { self.0.shutdown_unchecked() }
+			}
+		}
+
+		let _lock = lock_runtime();
+		let ctx = ShutdownOnDrop(Context::init().expect("failed to initialize OpenVR"));
+		f(&ctx.0);
+	}
+
+	static_assertions::assert_impl_all!(Context: Send);
+	static_assertions::assert_not_impl_any!(Context: Sync);
+
+	// Managers may be moved to another thread, but not shared between threads.
+	static_assertions::assert_impl_all!(OverlayManager<'static>: Send);
+	static_assertions::assert_not_impl_any!(OverlayManager<'static>: Sync);
+	#[cfg(feature = "ovr_system")]
+	static_assertions::assert_impl_all!(SystemManager<'static>: Send);
+	#[cfg(feature = "ovr_system")]
+	static_assertions::assert_not_impl_any!(SystemManager<'static>: Sync);
+	#[cfg(feature = "ovr_settings")]
+	static_assertions::assert_impl_all!(SettingsManager<'static>: Send);
+	#[cfg(feature = "ovr_settings")]
+	static_assertions::assert_not_impl_any!(SettingsManager<'static>: Sync);
+	#[cfg(feature = "ovr_chaperone")]
+	static_assertions::assert_impl_all!(ChaperoneManager<'static>: Send);
+	#[cfg(feature = "ovr_chaperone")]
+	static_assertions::assert_not_impl_any!(ChaperoneManager<'static>: Sync);
+	#[cfg(feature = "ovr_chaperone_setup")]
+	static_assertions::assert_impl_all!(ChaperoneSetupManager<'static>: Send);
+	#[cfg(feature = "ovr_chaperone_setup")]
+	static_assertions::assert_not_impl_any!(ChaperoneSetupManager<'static>: Sync);
+	#[cfg(feature = "ovr_input")]
+	static_assertions::assert_impl_all!(InputManager<'static>: Send);
+	#[cfg(feature = "ovr_input")]
+	static_assertions::assert_not_impl_any!(InputManager<'static>: Sync);
+	#[cfg(feature = "ovr_applications")]
+	static_assertions::assert_impl_all!(ApplicationsManager<'static>: Send);
+	#[cfg(feature = "ovr_applications")]
+	static_assertions::assert_not_impl_any!(ApplicationsManager<'static>: Sync);
+
+	#[test]
+	fn all_tracked_device_indices() {
+		let all: Vec<_> = TrackedDeviceIndex::all().collect();
+		assert_eq!(all.len(), TrackedDeviceIndex::MAX);
+		assert_eq!(all[0], TrackedDeviceIndex::HMD);
+		assert!(all.iter().all(|i| TrackedDeviceIndex::new(i.0).is_ok()));
+	}
+
+	#[test]
+	fn failed_init_can_be_retried() {
+		let _lock = lock_runtime();
+		// Without a running VR runtime, initialization fails. That failure must not
+		// leave the crate believing it is initialized.
+		let first = match Context::init() {
+			Err(err) => err,
+			// A runtime is available, so there is no failure to retry.
+			Ok(ctx) => return ctx.shutdown(),
+		};
+		assert!(
+			matches!(first, InitError::Sys(_)),
+			"unexpected error: {first}"
+		);
+		let second = match Context::init() {
+			Err(err) => err,
+			Ok(ctx) => return ctx.shutdown(),
+		};
+		assert!(
+			matches!(second, InitError::Sys(_)),
+			"unexpected error: {second}"
+		);
+	}
+
+	#[test]
+	fn init_recovers_from_poisoned_lock() {
+		let _lock = lock_runtime();
+		// Poison the lock by panicking while holding it.
+		let poisoner = std::thread::spawn(|| {
+			let _guard = INITIALIZED.lock();
+			panic!("poisoning INITIALIZED on purpose");
+		});
+		assert!(poisoner.join().is_err());
+		assert!(INITIALIZED.is_poisoned());
+
+		// A poisoned lock is not contention, and must not look like an existing
+		// context.
+		match Context::init() {
+			Ok(ctx) => ctx.shutdown(),
+			Err(err) => {
+				assert!(matches!(err, InitError::Sys(_)), "unexpected error: {err}")
+			}
+		}
+	}
+
+	#[test]
+	fn color_tint_constructors() {
+		assert_eq!(ColorTint::new(f32::NAN, 1.0, 1.0, 1.0), Err(()));
+		assert_eq!(ColorTint::new(1.0, -0.5, 1.0, 1.0), Err(()));
+		assert_eq!(ColorTint::new(1.0, 1.0, 1.0, 1.0), Ok(ColorTint::default()));
+
+		let tint = ColorTint::from_rgba8(0, 1, 128, 255);
+		let to_u8 = |c: f32| (c * 255.0).round() as u8;
+		assert_eq!(
+			[to_u8(tint.r), to_u8(tint.g), to_u8(tint.b), to_u8(tint.a)],
+			[0, 1, 128, 255]
+		);
+	}
+
+	#[test]
+	fn color_tint_clamp_alpha() {
+		let hdr = ColorTint::new(4.0, 2.0, 1.0, 1.5).unwrap();
+		assert_eq!(
+			hdr.clamp_alpha(),
+			ColorTint::new(4.0, 2.0, 1.0, 1.0).unwrap()
+		);
+		let transparent = ColorTint { a: -1.0, ..hdr }.clamp_alpha();
+		assert_eq!(transparent.a, 0.0);
+	}
+
+	#[test]
+	fn flip_texture_bounds() {
+		let bounds = TextureBounds::new(0.1, 0.2, 0.3, 0.4);
+		let flipped = bounds.clone().flip_vertical();
+		assert_eq!(
+			(
+				flipped.0.uMin,
+				flipped.0.vMin,
+				flipped.0.uMax,
+				flipped.0.vMax
+			),
+			(0.1, 0.4, 0.3, 0.2)
+		);
+		let flipped = bounds.flip_horizontal();
+		assert_eq!(
+			(
+				flipped.0.uMin,
+				flipped.0.vMin,
+				flipped.0.uMax,
+				flipped.0.vMax
+			),
+			(0.3, 0.2, 0.1, 0.4)
+		);
+
+		let mirrored = TextureBounds::full().flip_horizontal();
+		assert_eq!((mirrored.0.uMin, mirrored.0.uMax), (1.0, 0.0));
+		assert!(mirrored.is_finite());
+		assert!(!TextureBounds::new(0.0, f32::NAN, 1.0, 1.0).is_finite());
+	}
+
+	#[cfg(feature = "runtime-tests")]
+	#[test]
+	fn reinit_after_shutdown() {
+		let _lock = lock_runtime();
+		for _ in 0..2 {
+			let ctx = Context::init().expect("failed to initialize OpenVR");
+			let mut mngr = ctx.overlay_mngr();
+			let overlay = mngr
+				.create_overlay("ovr_overlay.tests.reinit", "Reinit")
+				.unwrap();
+			mngr.destroy_overlay(overlay).unwrap();
+			ctx.shutdown();
+		}
+	}
+
 	#[test]
 	fn ensure_testing_optional_features() {
 		macro_rules! helper {
             ($($feature:literal),+ $(,)?) => {
-                $(assert!(cfg!(feature = $feature), "use `cargo test --all-features` instead!"));+
+                $(assert!(cfg!(feature = $feature), "enable every feature except `runtime-tests`, see .github/workflows/main-ci.yml"));+
             };
         }
 
-		helper!("nalgebra");
+		helper!("nalgebra", "glam", "image", "serde", "testing");
 	}
 }
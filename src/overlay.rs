@@ -1,24 +1,43 @@
 pub use crate::errors::EVROverlayError;
+#[cfg(feature = "image")]
+pub use crate::errors::SetImageError;
+use crate::event::RawEvent;
 use crate::pose::Matrix3x4;
 use crate::pose::TrackingUniverseOrigin;
 use crate::TextureBounds;
 use crate::{sys, ColorTint, Context, TrackedDeviceIndex};
 
-use derive_more::From;
+use derive_more::{From, Into};
+use enumset::{EnumSet, EnumSetType};
+use std::ffi::{c_char, c_void, CStr, CString};
 use std::marker::PhantomData;
+use std::mem::MaybeUninit;
+use std::path::Path;
 use std::pin::Pin;
+use std::ptr::null_mut;
 
 pub struct OverlayManager<'c> {
 	ctx: PhantomData<&'c Context>,
 	inner: Pin<&'c mut sys::IVROverlay>,
+	/// Events from [`Self::inject_test_event()`], returned before any real events.
+	#[cfg(feature = "testing")]
+	test_events: TestEventQueue,
 }
 impl<'c> OverlayManager<'c> {
 	pub(super) fn new(_ctx: &'c Context) -> Self {
+		Self::for_context(PhantomData)
+	}
+
+	/// Like [`Self::new()`], for when only the lifetime of the context is at hand,
+	/// such as in [`ScopedOverlay`]'s `Drop`.
+	fn for_context(ctx: PhantomData<&'c Context>) -> Self {
 		let inner =
 			unsafe { Pin::new_unchecked(sys::VROverlay().as_mut::<'c>().unwrap()) };
 		Self {
-			ctx: Default::default(),
+			ctx,
 			inner,
+			#[cfg(feature = "testing")]
+			test_events: Default::default(),
 		}
 	}
 
@@ -27,11 +46,12 @@ impl<'c> OverlayManager<'c> {
 		key: &str,
 		friendly_name: &str,
 	) -> Result<OverlayHandle, EVROverlayError> {
+		let (key, friendly_name) = to_cstrings(key, friendly_name)?;
 		let mut handle = sys::VROverlayHandle_t::default();
 		let err = unsafe {
 			self.inner.as_mut().CreateOverlay(
-				key.as_ptr().cast(),
-				friendly_name.as_ptr().cast(),
+				key.as_ptr(),
+				friendly_name.as_ptr(),
 				&mut handle,
 			)
 		};
@@ -40,6 +60,338 @@ impl<'c> OverlayManager<'c> {
 		Ok(OverlayHandle(handle))
 	}
 
+	/// Same as [`Self::create_overlay()`], but the overlay is destroyed again when
+	/// the returned [`ScopedOverlay`] is dropped.
+	pub fn create_overlay_scoped(
+		&mut self,
+		key: &str,
+		friendly_name: &str,
+	) -> Result<ScopedOverlay<'c>, EVROverlayError> {
+		let overlay = self.create_overlay(key, friendly_name)?;
+		Ok(ScopedOverlay {
+			ctx: self.ctx,
+			overlay,
+		})
+	}
+
+	/// Looks up an existing overlay by its key, which may also have been created by
+	/// another process. Returns `VROverlayError_UnknownOverlay` if there is none.
+	pub fn find_overlay(
+		&mut self,
+		key: &str,
+	) -> Result<OverlayHandle, EVROverlayError> {
+		let key = to_cstring(key)?;
+		let mut handle = sys::VROverlayHandle_t::default();
+		let err = unsafe { self.inner.as_mut().FindOverlay(key.as_ptr(), &mut handle) };
+
+		EVROverlayError::new(err)?;
+		Ok(OverlayHandle(handle))
+	}
+
+	/// Creates an overlay that shows up as a tab in the SteamVR dashboard.
+	///
+	/// Returns the handle of the main overlay, followed by the handle of the
+	/// thumbnail overlay used as the tab's icon. The main overlay is shown while the
+	/// tab is selected and is populated like any other overlay. The thumbnail starts
+	/// out empty and the tab shows up blank, so remember to set an image on it too,
+	/// see [`Self::set_dashboard_thumbnail_image()`].
+	pub fn create_dashboard_overlay(
+		&mut self,
+		key: &str,
+		friendly_name: &str,
+	) -> Result<(OverlayHandle, OverlayHandle), EVROverlayError> {
+		let (key, friendly_name) = to_cstrings(key, friendly_name)?;
+		let mut main = sys::VROverlayHandle_t::default();
+		let mut thumbnail = sys::VROverlayHandle_t::default();
+		let err = unsafe {
+			self.inner.as_mut().CreateDashboardOverlay(
+				key.as_ptr(),
+				friendly_name.as_ptr(),
+				&mut main,
+				&mut thumbnail,
+			)
+		};
+
+		EVROverlayError::new(err)?;
+		Ok((OverlayHandle(main), OverlayHandle(thumbnail)))
+	}
+
+	/// Sets the icon of a dashboard tab, where `thumbnail` is the second handle
+	/// returned by [`Self::create_dashboard_overlay()`].
+	///
+	/// This is the same as [`Self::set_image_path()`] on the thumbnail overlay.
+	pub fn set_dashboard_thumbnail_image(
+		&mut self,
+		thumbnail: OverlayHandle,
+		path: &Path,
+	) -> Result<(), EVROverlayError> {
+		self.set_image_path(thumbnail, path)
+	}
+
+	pub fn is_dashboard_visible(&mut self) -> bool {
+		unsafe { self.inner.as_mut().IsDashboardVisible() }
+	}
+
+	/// Whether `overlay` is the dashboard overlay whose tab is currently selected.
+	/// Dashboard overlays usually only need to process input while this is true.
+	pub fn is_active_dashboard_overlay(&mut self, overlay: OverlayHandle) -> bool {
+		unsafe { self.inner.as_mut().IsActiveDashboardOverlay(overlay.0) }
+	}
+
+	/// Gets the device that opened the dashboard, or `None` if the dashboard isn't
+	/// open or wasn't opened by a device.
+	pub fn get_primary_dashboard_device(&mut self) -> Option<TrackedDeviceIndex> {
+		let index = unsafe { self.inner.as_mut().GetPrimaryDashboardDevice() };
+		TrackedDeviceIndex::new(index).ok()
+	}
+
+	/// Associates a dashboard overlay with a scene application's process, so that
+	/// the dashboard shows it while that application is running.
+	pub fn set_dashboard_overlay_scene_process(
+		&mut self,
+		overlay: OverlayHandle,
+		process_id: u32,
+	) -> Result<(), EVROverlayError> {
+		let err = unsafe {
+			self.inner
+				.as_mut()
+				.SetDashboardOverlaySceneProcess(overlay.0, process_id)
+		};
+		EVROverlayError::new(err)
+	}
+
+	pub fn dashboard_overlay_scene_process(
+		&mut self,
+		overlay: OverlayHandle,
+	) -> Result<u32, EVROverlayError> {
+		let mut process_id = 0;
+		let err = unsafe {
+			self.inner
+				.as_mut()
+				.GetDashboardOverlaySceneProcess(overlay.0, &mut process_id)
+		};
+		EVROverlayError::new(err)?;
+		Ok(process_id)
+	}
+
+	/// Destroys the overlay. The handle is invalid afterwards.
+	pub fn destroy_overlay(
+		&mut self,
+		overlay: OverlayHandle,
+	) -> Result<(), EVROverlayError> {
+		let err = unsafe { self.inner.as_mut().DestroyOverlay(overlay.0) };
+		EVROverlayError::new(err)
+	}
+
+	/// Changes the friendly name of an existing overlay, keeping its texture and
+	/// transform.
+	pub fn set_name(
+		&mut self,
+		overlay: OverlayHandle,
+		friendly_name: &str,
+	) -> Result<(), EVROverlayError> {
+		let friendly_name = to_cstring(friendly_name)?;
+		let err = unsafe {
+			self.inner
+				.as_mut()
+				.SetOverlayName(overlay.0, friendly_name.as_ptr())
+		};
+		EVROverlayError::new(err)
+	}
+
+	/// Gets the unique key the overlay was created with.
+	///
+	/// Wraps c++ `GetOverlayKey`.
+	pub fn key(&mut self, overlay: OverlayHandle) -> Result<String, EVROverlayError> {
+		self.get_string(|inner, buf, len, err| unsafe {
+			inner.GetOverlayKey(overlay.0, buf, len, err)
+		})
+	}
+
+	/// Gets the friendly name of the overlay.
+	///
+	/// Wraps c++ `GetOverlayName`.
+	pub fn name(&mut self, overlay: OverlayHandle) -> Result<String, EVROverlayError> {
+		self.get_string(|inner, buf, len, err| unsafe {
+			inner.GetOverlayName(overlay.0, buf, len, err)
+		})
+	}
+
+	/// Reads a string from a getter that returns the required buffer size when
+	/// called with an empty buffer.
+	fn get_string(
+		&mut self,
+		mut getter: impl FnMut(
+			Pin<&mut sys::IVROverlay>,
+			*mut c_char,
+			u32,
+			*mut sys::EVROverlayError,
+		) -> u32,
+	) -> Result<String, EVROverlayError> {
+		let mut err = sys::EVROverlayError::VROverlayError_None;
+		let len = getter(self.inner.as_mut(), null_mut(), 0, &mut err);
+		if err != sys::EVROverlayError::VROverlayError_ArrayTooSmall {
+			EVROverlayError::new(err)?;
+		}
+		if len == 0 {
+			return Ok(String::new());
+		}
+
+		let mut data = vec![0u8; len as usize];
+		err = sys::EVROverlayError::VROverlayError_None;
+		getter(self.inner.as_mut(), data.as_mut_ptr().cast(), len, &mut err);
+		EVROverlayError::new(err)?;
+
+		let s =
+			CStr::from_bytes_until_nul(&data).expect("missing nul byte from openvr!");
+		Ok(s.to_string_lossy().into_owned())
+	}
+
+	/// Gets all flags that are set on the overlay.
+	///
+	/// Wraps c++ `GetOverlayFlags`.
+	pub fn flags(
+		&mut self,
+		overlay: OverlayHandle,
+	) -> Result<EnumSet<OverlayFlag>, EVROverlayError> {
+		let mut flags = 0;
+		let err = unsafe { self.inner.as_mut().GetOverlayFlags(overlay.0, &mut flags) };
+		EVROverlayError::new(err)?;
+		// Flags unknown to this crate are dropped.
+		Ok(EnumSet::from_repr_truncated(flags))
+	}
+
+	/// Gets whether a single flag is set on the overlay.
+	///
+	/// Wraps c++ `GetOverlayFlag`.
+	pub fn flag(
+		&mut self,
+		overlay: OverlayHandle,
+		flag: OverlayFlag,
+	) -> Result<bool, EVROverlayError> {
+		let mut enabled = false;
+		let err = unsafe {
+			self.inner
+				.as_mut()
+				.GetOverlayFlag(overlay.0, flag.into(), &mut enabled)
+		};
+		EVROverlayError::new(err)?;
+		Ok(enabled)
+	}
+
+	/// Enables or disables a single flag on the overlay. Flags that the runtime
+	/// doesn't support result in an error from OpenVR.
+	///
+	/// Wraps c++ `SetOverlayFlag`.
+	pub fn set_flag(
+		&mut self,
+		overlay: OverlayHandle,
+		flag: OverlayFlag,
+		enabled: bool,
+	) -> Result<(), EVROverlayError> {
+		let err = unsafe {
+			self.inner
+				.as_mut()
+				.SetOverlayFlag(overlay.0, flag.into(), enabled)
+		};
+		EVROverlayError::new(err)
+	}
+
+	/// Sets [`OverlayFlag::MakeOverlaysInteractiveIfVisible`], which makes the
+	/// laser pointer available while the overlay is visible, even outside of the
+	/// dashboard.
+	///
+	/// For the overlay to receive events from the laser pointer, it also needs an
+	/// input method from [`Self::set_input_method()`]. Events are then read with
+	/// [`Self::poll_next_event()`].
+	pub fn set_interactive_if_visible(
+		&mut self,
+		overlay: OverlayHandle,
+		enabled: bool,
+	) -> Result<(), EVROverlayError> {
+		self.set_flag(
+			overlay,
+			OverlayFlag::MakeOverlaysInteractiveIfVisible,
+			enabled,
+		)
+	}
+
+	/// Sets [`OverlayFlag::IsPremultiplied`], which tells OpenVR that the color
+	/// channels of the texture are already multiplied by its alpha. Without it,
+	/// textures with premultiplied alpha get darker edges where they are
+	/// transparent.
+	///
+	/// The alpha of [`Self::set_tint()`] and [`Self::set_opacity()`] is applied on
+	/// top of the texture either way, and fades the whole overlay.
+	pub fn set_premultiplied_alpha(
+		&mut self,
+		overlay: OverlayHandle,
+		premultiplied: bool,
+	) -> Result<(), EVROverlayError> {
+		self.set_flag(overlay, OverlayFlag::IsPremultiplied, premultiplied)
+	}
+
+	/// Sets the process that is allowed to render into the overlay, which allows a
+	/// renderer process to take over an overlay created by another process.
+	pub fn set_rendering_pid(
+		&mut self,
+		overlay: OverlayHandle,
+		pid: u32,
+	) -> Result<(), EVROverlayError> {
+		let err = unsafe { self.inner.as_mut().SetOverlayRenderingPid(overlay.0, pid) };
+		EVROverlayError::new(err)
+	}
+
+	/// Gets the process that is allowed to render into the overlay.
+	pub fn rendering_pid(&mut self, overlay: OverlayHandle) -> u32 {
+		unsafe { self.inner.as_mut().GetOverlayRenderingPid(overlay.0) }
+	}
+
+	/// Starts a chain of settings to apply to an existing overlay. Nothing is
+	/// changed until [`OverlayConfig::apply()`] is called.
+	pub fn configure(&mut self, overlay: OverlayHandle) -> OverlayConfig<'_, 'c> {
+		OverlayConfig {
+			mngr: self,
+			overlay,
+			settings: OverlaySettings::default(),
+		}
+	}
+
+	/// Applies every setting in `settings`. Settings are all attempted even if an
+	/// earlier one fails, and the first error encountered is returned.
+	///
+	/// Unlike [`Self::set_opacity()`], an opacity outside of `[0,1]` doesn't panic
+	/// but returns `VROverlayError_InvalidParameter`.
+	pub fn apply_settings(
+		&mut self,
+		overlay: OverlayHandle,
+		settings: &OverlaySettings,
+	) -> Result<(), EVROverlayError> {
+		let results = [
+			settings.width.map(|w| self.set_width(overlay, w)),
+			settings.curvature.map(|c| self.set_curvature(overlay, c)),
+			settings.tint.map(|t| self.set_tint(overlay, t)),
+			settings.opacity.map(|a| {
+				validate_opacity(a)?;
+				self.set_opacity(overlay, a)
+			}),
+		];
+		results.into_iter().flatten().collect()
+	}
+
+	/// Creates an overlay for each `(key, friendly_name, settings)` and applies its
+	/// settings.
+	///
+	/// If any overlay can't be created or configured, the overlays created so far
+	/// are destroyed again before returning the error, so either all overlays
+	/// exist or none do.
+	pub fn create_overlays(
+		&mut self,
+		defs: &[(String, String, OverlaySettings)],
+	) -> Result<Vec<OverlayHandle>, EVROverlayError> {
+		create_overlays(self, defs)
+	}
+
 	pub fn set_visibility(
 		&mut self,
 		overlay: OverlayHandle,
@@ -54,21 +406,68 @@ impl<'c> OverlayManager<'c> {
 		EVROverlayError::new(err)
 	}
 
+	/// Whether the overlay is visible. Invalid handles are reported as not
+	/// visible; use [`Self::visibility()`] to tell the two apart.
 	pub fn is_visible(&mut self, overlay: OverlayHandle) -> bool {
 		unsafe { self.inner.as_mut().IsOverlayVisible(overlay.0) }
 	}
 
+	/// Same as [`Self::is_visible()`], but returns
+	/// `VROverlayError_InvalidHandle` if `overlay` doesn't refer to a live
+	/// overlay instead of reporting it as hidden.
+	pub fn visibility(
+		&mut self,
+		overlay: OverlayHandle,
+	) -> Result<bool, EVROverlayError> {
+		self.validate_handle(overlay)?;
+		Ok(self.is_visible(overlay))
+	}
+
+	/// Cheaply checks that `overlay` refers to a live overlay, by reading its flags.
+	fn validate_handle(
+		&mut self,
+		overlay: OverlayHandle,
+	) -> Result<(), EVROverlayError> {
+		let mut flags = 0;
+		let err = unsafe { self.inner.as_mut().GetOverlayFlags(overlay.0, &mut flags) };
+		EVROverlayError::new(err)
+	}
+
+	/// Sets the sort order of the overlay. Overlays with a higher sort order are
+	/// drawn on top of overlays with a lower one, which keeps overlays at the same
+	/// depth from flickering.
+	pub fn set_sort_order(
+		&mut self,
+		overlay: OverlayHandle,
+		order: u32,
+	) -> Result<(), EVROverlayError> {
+		let err = unsafe { self.inner.as_mut().SetOverlaySortOrder(overlay.0, order) };
+		EVROverlayError::new(err)
+	}
+
+	pub fn sort_order(
+		&mut self,
+		overlay: OverlayHandle,
+	) -> Result<u32, EVROverlayError> {
+		let mut order = 0;
+		let err = unsafe {
+			self.inner
+				.as_mut()
+				.GetOverlaySortOrder(overlay.0, &mut order)
+		};
+		EVROverlayError::new(err)?;
+		Ok(order)
+	}
+
 	/// Set the curvature of the overlay, with 0 being a quad and 1 being a cylinder.
-	/// # Panics
-	/// Panics if `curvature` is not in `[0,1]`
+	///
+	/// Returns `VROverlayError_InvalidParameter` if `curvature` is not in `[0,1]`.
 	pub fn set_curvature(
 		&mut self,
 		overlay: OverlayHandle,
 		curvature: f32,
 	) -> Result<(), EVROverlayError> {
-		// if !(0.0..=1.0).contains(&curvature) {
-		//     panic!("`curvature` must lie in range [0,1]")
-		// }
+		validate_curvature(curvature)?;
 		let err = unsafe {
 			self.inner
 				.as_mut()
@@ -77,10 +476,34 @@ impl<'c> OverlayManager<'c> {
 		EVROverlayError::new(err)
 	}
 
+	/// Curves the overlay so that it wraps around a cylinder of `radius_meters`.
+	///
+	/// A curvature of 1 closes the overlay into a full cylinder, so its width is
+	/// the circumference, and the curvature for a radius `r` is
+	/// `width / (2 * PI * r)`. Radii smaller than `width / (2 * PI)` can't be
+	/// represented, so the curvature is clamped to `[0,1]`.
+	///
+	/// Returns `VROverlayError_InvalidParameter` if `radius_meters` is not positive.
+	pub fn set_curvature_for_radius(
+		&mut self,
+		overlay: OverlayHandle,
+		radius_meters: f32,
+	) -> Result<(), EVROverlayError> {
+		if radius_meters.is_nan() || radius_meters <= 0.0 {
+			return EVROverlayError::new(
+				sys::EVROverlayError::VROverlayError_InvalidParameter,
+			);
+		}
+		let width = self.width(overlay)?;
+		self.set_curvature(overlay, curvature_for_radius(width, radius_meters))
+	}
+
 	pub fn curvature(
 		&mut self,
 		overlay: OverlayHandle,
 	) -> Result<f32, EVROverlayError> {
+		// Some runtimes report success with a default value for invalid handles.
+		self.validate_handle(overlay)?;
 		let mut curvature = 0.0;
 		let err = unsafe {
 			self.inner
@@ -107,6 +530,8 @@ impl<'c> OverlayManager<'c> {
 	}
 
 	pub fn opacity(&mut self, overlay: OverlayHandle) -> Result<f32, EVROverlayError> {
+		// Some runtimes report success with a default value for invalid handles.
+		self.validate_handle(overlay)?;
 		let mut alpha = 0.0;
 		let err = unsafe { self.inner.as_mut().GetOverlayAlpha(overlay.0, &mut alpha) };
 		EVROverlayError::new(err)?;
@@ -114,6 +539,8 @@ impl<'c> OverlayManager<'c> {
 	}
 
 	pub fn width(&mut self, overlay: OverlayHandle) -> Result<f32, EVROverlayError> {
+		// Some runtimes report success with a default value for invalid handles.
+		self.validate_handle(overlay)?;
 		let mut width = 0.0;
 		let err = unsafe {
 			self.inner
@@ -124,11 +551,24 @@ impl<'c> OverlayManager<'c> {
 		Ok(width)
 	}
 
+	/// Sets the width of the overlay in meters. The height is derived from the
+	/// width and the texel aspect.
+	///
+	/// Returns `VROverlayError_InvalidParameter` if `width_in_meters` is not a
+	/// finite, positive number, as such overlays would be invisible or inverted.
+	/// Any other width is passed to OpenVR unchanged.
+	// TODO: document the largest width SteamVR accepts, once it has been measured
+	// against a runtime. openvr.h doesn't specify one.
 	pub fn set_width(
 		&mut self,
 		overlay: OverlayHandle,
 		width_in_meters: f32,
 	) -> Result<(), EVROverlayError> {
+		if !width_in_meters.is_finite() || width_in_meters <= 0.0 {
+			return EVROverlayError::new(
+				sys::EVROverlayError::VROverlayError_InvalidParameter,
+			);
+		}
 		let err = unsafe {
 			self.inner
 				.as_mut()
@@ -156,11 +596,25 @@ impl<'c> OverlayManager<'c> {
 		Ok(tint)
 	}
 
+	/// Sets the color and alpha of the overlay.
+	///
+	/// Color channels above 1.0 are allowed for HDR overlays, but negative or
+	/// non-finite channels return `VROverlayError_InvalidParameter`. Alpha is
+	/// clamped to `[0,1]`, see [`ColorTint::clamp_alpha()`].
 	pub fn set_tint(
 		&mut self,
 		overlay: OverlayHandle,
 		tint: ColorTint,
 	) -> Result<(), EVROverlayError> {
+		let valid_color = [tint.r, tint.g, tint.b]
+			.iter()
+			.all(|c| c.is_finite() && *c >= 0.0);
+		if !valid_color || !tint.a.is_finite() {
+			return EVROverlayError::new(
+				sys::EVROverlayError::VROverlayError_InvalidParameter,
+			);
+		}
+		let tint = tint.clamp_alpha();
 		unsafe {
 			let err = self
 				.inner
@@ -173,6 +627,15 @@ impl<'c> OverlayManager<'c> {
 		Ok(())
 	}
 
+	pub fn set_image_path(
+		&mut self,
+		overlay: OverlayHandle,
+		img_path: &Path,
+	) -> Result<(), EVROverlayError> {
+		let img_path = to_cstring(img_path.to_string_lossy().as_bytes())?;
+		self.set_image(overlay, &img_path)
+	}
+
 	pub fn set_image(
 		&mut self,
 		overlay: OverlayHandle,
@@ -211,6 +674,103 @@ impl<'c> OverlayManager<'c> {
 		EVROverlayError::new(err)
 	}
 
+	/// Decodes an in-memory PNG or JPEG image and sets it as the overlay's
+	/// texture, without needing to write it to a file first.
+	///
+	/// Returns [`SetImageError::Decode`] with the reason if the image can't be
+	/// decoded.
+	#[cfg(feature = "image")]
+	pub fn set_image_from_bytes(
+		&mut self,
+		overlay: OverlayHandle,
+		png_or_jpg: &[u8],
+	) -> Result<(), SetImageError> {
+		let img = image::load_from_memory(png_or_jpg)?.into_rgba8();
+		let (width, height) = img.dimensions();
+		self.set_raw_data(overlay, &img, width as usize, height as usize, 4)?;
+		Ok(())
+	}
+
+	/// Same as [`Self::set_image_from_bytes()`], but reports decoding failures as
+	/// `VROverlayError_InvalidParameter`, logging the reason as a warning.
+	#[cfg(feature = "image")]
+	pub fn set_raw_image_bytes(
+		&mut self,
+		overlay: OverlayHandle,
+		png_or_jpg: &[u8],
+	) -> Result<(), EVROverlayError> {
+		match self.set_image_from_bytes(overlay, png_or_jpg) {
+			Ok(()) => Ok(()),
+			Err(SetImageError::Overlay(err)) => Err(err),
+			Err(SetImageError::Decode(err)) => {
+				log::warn!("failed to decode overlay image: {err}");
+				EVROverlayError::new(
+					sys::EVROverlayError::VROverlayError_InvalidParameter,
+				)
+			}
+		}
+	}
+
+	/// Sets whether the overlay's texture holds gamma or linear colors. OpenVR
+	/// guesses when this is [`ColorSpace::Auto`], which is often wrong for 4-channel
+	/// data from [`Self::set_raw_data()`].
+	pub fn set_texture_color_space(
+		&mut self,
+		overlay: OverlayHandle,
+		space: ColorSpace,
+	) -> Result<(), EVROverlayError> {
+		let err = unsafe {
+			self.inner
+				.as_mut()
+				.SetOverlayTextureColorSpace(overlay.0, space.into())
+		};
+		EVROverlayError::new(err)
+	}
+
+	pub fn texture_color_space(
+		&mut self,
+		overlay: OverlayHandle,
+	) -> Result<ColorSpace, EVROverlayError> {
+		let mut space = sys::EColorSpace::ColorSpace_Auto;
+		let err = unsafe {
+			self.inner
+				.as_mut()
+				.GetOverlayTextureColorSpace(overlay.0, &mut space)
+		};
+		EVROverlayError::new(err)?;
+		Ok(space.into())
+	}
+
+	/// Gets the size of the overlay's texture in pixels, as `(width, height)`.
+	///
+	/// Wraps c++ `GetOverlayTextureSize`.
+	pub fn texture_size(
+		&mut self,
+		overlay: OverlayHandle,
+	) -> Result<(u32, u32), EVROverlayError> {
+		let mut width = 0;
+		let mut height = 0;
+		let err = unsafe {
+			self.inner.as_mut().GetOverlayTextureSize(
+				overlay.0,
+				&mut width,
+				&mut height,
+			)
+		};
+		EVROverlayError::new(err)?;
+		Ok((width, height))
+	}
+
+	/// Whether the overlay has a texture, such as to check if an overlay found
+	/// through [`Self::find_overlay()`] still needs to be populated.
+	///
+	/// This is a heuristic on top of [`Self::texture_size()`]: OpenVR has no direct
+	/// query, so an overlay counts as textured when its texture size can be read
+	/// and isn't zero.
+	pub fn has_texture(&mut self, overlay: OverlayHandle) -> bool {
+		matches!(self.texture_size(overlay), Ok((w, h)) if w > 0 && h > 0)
+	}
+
 	/// Get aspect ratio, with aspect expressed as width / height.
 	pub fn texel_aspect(
 		&mut self,
@@ -239,6 +799,29 @@ impl<'c> OverlayManager<'c> {
 		EVROverlayError::new(err)
 	}
 
+	/// Sets the physical size of the overlay, by setting its width and setting the
+	/// texel aspect to `width / height`.
+	///
+	/// OpenVR applies the texel aspect on top of the texture's own aspect ratio, so
+	/// the overlay is exactly `height` tall only for square textures.
+	///
+	/// Returns `VROverlayError_InvalidParameter` if either dimension is not a finite,
+	/// positive number.
+	pub fn set_size_meters(
+		&mut self,
+		overlay: OverlayHandle,
+		width: f32,
+		height: f32,
+	) -> Result<(), EVROverlayError> {
+		if !height.is_finite() || height <= 0.0 {
+			return EVROverlayError::new(
+				sys::EVROverlayError::VROverlayError_InvalidParameter,
+			);
+		}
+		self.set_width(overlay, width)?;
+		self.set_texel_aspect(overlay, width / height)
+	}
+
 	/// Sets an absolute transform for this overlay.
 	///
 	/// Wraps c++ `SetOverlayTransformAbsolute`.
@@ -259,10 +842,37 @@ impl<'c> OverlayManager<'c> {
 		EVROverlayError::new(err)
 	}
 
-	/// Gets the absolute transform for this overlay.
+	/// Places the overlay `distance` meters in front of the HMD, facing it, using
+	/// the current HMD pose from `system`. The overlay stays there in the standing
+	/// universe and does not follow the HMD afterwards.
 	///
-	/// Wraps c++ `GetOverlayTransformAbsolute`.
-	pub fn get_transform_absolute(
+	/// Returns `VROverlayError_RequestFailed` if the HMD isn't being tracked.
+	#[cfg(feature = "ovr_system")]
+	pub fn place_in_front_of_hmd(
+		&mut self,
+		overlay: OverlayHandle,
+		system: &mut crate::system::SystemManager,
+		distance: f32,
+	) -> Result<(), EVROverlayError> {
+		let origin = TrackingUniverseOrigin::TrackingUniverseStanding;
+		let Some(hmd) = system
+			.pose_for_device_predicted(TrackedDeviceIndex::HMD, origin, 0.)
+			.filter(|pose| pose.pose_is_valid())
+		else {
+			return EVROverlayError::new(sys::EVROverlayError::VROverlayError_RequestFailed);
+		};
+		// The HMD looks along its -Z axis, which is the third column of the basis.
+		let mut transform = hmd.device_to_absolute_tracking();
+		for row in &mut transform.0 {
+			row[3] -= row[2] * distance;
+		}
+		self.set_transform_absolute(overlay, origin, &transform)
+	}
+
+	/// Gets the absolute transform for this overlay.
+	///
+	/// Wraps c++ `GetOverlayTransformAbsolute`.
+	pub fn get_transform_absolute(
 		&mut self,
 		overlay: OverlayHandle,
 		origin_to_overlay: &mut Matrix3x4,
@@ -280,6 +890,41 @@ impl<'c> OverlayManager<'c> {
 		EVROverlayError::new(err).map(|_| origin)
 	}
 
+	/// Reads the layout of an overlay, so that it can be saved and later applied
+	/// again with [`Self::restore()`].
+	///
+	/// The overlay must have an absolute transform, otherwise this returns
+	/// `VROverlayError_WrongTransformType`.
+	pub fn snapshot(
+		&mut self,
+		overlay: OverlayHandle,
+	) -> Result<OverlaySnapshot, EVROverlayError> {
+		let mut transform = Matrix3x4([[0.; 4]; 3]);
+		let origin = self.get_transform_absolute(overlay, &mut transform)?;
+		Ok(OverlaySnapshot {
+			width: self.width(overlay)?,
+			curvature: self.curvature(overlay)?,
+			tint: self.tint(overlay)?,
+			sort_order: self.sort_order(overlay)?,
+			origin,
+			transform,
+		})
+	}
+
+	/// Applies a layout read with [`Self::snapshot()`], stopping at the first
+	/// setting that fails.
+	pub fn restore(
+		&mut self,
+		overlay: OverlayHandle,
+		snapshot: &OverlaySnapshot,
+	) -> Result<(), EVROverlayError> {
+		self.set_width(overlay, snapshot.width)?;
+		self.set_curvature(overlay, snapshot.curvature)?;
+		self.set_tint(overlay, snapshot.tint)?;
+		self.set_sort_order(overlay, snapshot.sort_order)?;
+		self.set_transform_absolute(overlay, snapshot.origin, &snapshot.transform)
+	}
+
 	/// Sets the transform for this overlay, relative to a tracked device.
 	///
 	/// Wraps c++ `SetOverlayTransformTrackedDeviceRelative`.
@@ -369,11 +1014,20 @@ impl<'c> OverlayManager<'c> {
 		EVROverlayError::new(err).map(|_| parent_overlay.into())
 	}
 
+	/// Sets the region of the texture that the overlay shows. Bounds may lie
+	/// outside of `[0,1]`, for example to mirror or tile the texture.
+	///
+	/// Returns `VROverlayError_InvalidParameter` if any of the bounds is not finite.
 	pub fn set_texture_bounds(
 		&mut self,
 		overlay: OverlayHandle,
 		bounds: &TextureBounds,
 	) -> Result<(), EVROverlayError> {
+		if !bounds.is_finite() {
+			return EVROverlayError::new(
+				sys::EVROverlayError::VROverlayError_InvalidParameter,
+			);
+		}
 		let err = unsafe {
 			self.inner
 				.as_mut()
@@ -381,9 +1035,1282 @@ impl<'c> OverlayManager<'c> {
 		};
 		EVROverlayError::new(err)
 	}
+
+	/// Gets the region of the texture that the overlay shows.
+	///
+	/// Wraps c++ `GetOverlayTextureBounds`.
+	pub fn texture_bounds(
+		&mut self,
+		overlay: OverlayHandle,
+	) -> Result<TextureBounds, EVROverlayError> {
+		let mut bounds = TextureBounds::full();
+		let err = unsafe {
+			self.inner
+				.as_mut()
+				.GetOverlayTextureBounds(overlay.0, &mut bounds.0)
+		};
+		EVROverlayError::new(err)?;
+		Ok(bounds)
+	}
+
+	/// Sets a GPU texture as the overlay's content.
+	///
+	/// OpenVR takes a single texture per overlay, so stereo content has to be packed
+	/// side by side into one texture, with [`OverlayFlag::SideBySideParallel`] or
+	/// [`OverlayFlag::SideBySideCrossed`] set on the overlay.
+	///
+	/// Wraps c++ `SetOverlayTexture`.
+	pub fn set_texture(
+		&mut self,
+		overlay: OverlayHandle,
+		texture: &Texture,
+	) -> Result<(), EVROverlayError> {
+		let err =
+			unsafe { self.inner.as_mut().SetOverlayTexture(overlay.0, &texture.0) };
+		EVROverlayError::new(err)
+	}
+
+	/// Sets a Vulkan image as the overlay's content.
+	///
+	/// Wraps c++ `SetOverlayTexture` with a `TextureType_Vulkan` texture.
+	///
+	/// # Safety
+	/// The handles in `texture` must refer to live Vulkan objects, and the image
+	/// must stay valid until it has been submitted.
+	pub unsafe fn set_texture_vulkan(
+		&mut self,
+		overlay: OverlayHandle,
+		texture: &VulkanTextureData,
+	) -> Result<(), EVROverlayError> {
+		// `VulkanTextureData` has the layout of `VRVulkanTextureData_t`, so it can be
+		// handed to openvr as is.
+		let handle = texture as *const VulkanTextureData as *mut c_void;
+		let texture = Texture::new(
+			handle,
+			sys::ETextureType::TextureType_Vulkan,
+			sys::EColorSpace::ColorSpace_Auto,
+		);
+		self.set_texture(overlay, &texture)
+	}
+
+	/// Sets a D3D11 texture that was shared through a DXGI shared handle as the
+	/// overlay's content.
+	///
+	/// Wraps c++ `SetOverlayTexture` with a `TextureType_DXGISharedHandle` texture.
+	///
+	/// # Safety
+	/// `shared_handle` must be a valid DXGI shared handle, and must outlive the
+	/// submission. Closing it while the overlay still shows it is undefined
+	/// behavior.
+	pub unsafe fn set_texture_dxgi_shared(
+		&mut self,
+		overlay: OverlayHandle,
+		shared_handle: *mut c_void,
+		color_space: sys::EColorSpace,
+	) -> Result<(), EVROverlayError> {
+		let texture = Texture::new(
+			shared_handle,
+			sys::ETextureType::TextureType_DXGISharedHandle,
+			color_space,
+		);
+		self.set_texture(overlay, &texture)
+	}
+
+	/// Removes the texture from the overlay, so that it shows nothing until a new
+	/// one is set. This does not change the overlay's visibility.
+	///
+	/// Wraps c++ `ClearOverlayTexture`.
+	pub fn clear_texture(
+		&mut self,
+		overlay: OverlayHandle,
+	) -> Result<(), EVROverlayError> {
+		let err = unsafe { self.inner.as_mut().ClearOverlayTexture(overlay.0) };
+		EVROverlayError::new(err)
+	}
+
+	// ---- Keyboard ----
+
+	/// Shows the SteamVR keyboard. `description` is shown above the text field,
+	/// which starts out containing `existing_text`. `user_value` is passed back in
+	/// the keyboard events.
+	///
+	/// Input is finished when a `VREvent_KeyboardDone` event arrives, after which
+	/// the text can be read with [`Self::keyboard_text()`]. `VREvent_KeyboardClosed`
+	/// means the user dismissed the keyboard instead. Both show up as
+	/// [`OverlayEventKind::Other`] or `SystemEventKind::Other` with the matching
+	/// `event_type`.
+	///
+	/// Wraps c++ `ShowKeyboard`.
+	#[allow(clippy::too_many_arguments)] // mirrors the c++ signature
+	pub fn show_keyboard(
+		&mut self,
+		flags: EnumSet<KeyboardFlag>,
+		input_mode: sys::EGamepadTextInputMode,
+		line_mode: sys::EGamepadTextInputLineMode,
+		description: &str,
+		max_chars: u32,
+		existing_text: &str,
+		user_value: u64,
+	) -> Result<(), EVROverlayError> {
+		let description = to_cstring(description)?;
+		let existing_text = to_cstring(existing_text)?;
+		let err = unsafe {
+			self.inner.as_mut().ShowKeyboard(
+				input_mode,
+				line_mode,
+				flags.as_repr(),
+				description.as_ptr(),
+				max_chars,
+				existing_text.as_ptr(),
+				user_value,
+			)
+		};
+		EVROverlayError::new(err)
+	}
+
+	/// Gets the text that was typed into the keyboard.
+	///
+	/// Wraps c++ `GetKeyboardText`.
+	pub fn keyboard_text(&mut self) -> String {
+		let len = unsafe { self.inner.as_mut().GetKeyboardText(null_mut(), 0) };
+		if len == 0 {
+			return String::new();
+		}
+		let mut data = vec![0u8; len as usize + 1];
+		unsafe {
+			self.inner
+				.as_mut()
+				.GetKeyboardText(data.as_mut_ptr().cast(), data.len() as u32)
+		};
+		match CStr::from_bytes_until_nul(&data) {
+			Ok(text) => text.to_string_lossy().into_owned(),
+			Err(_) => String::from_utf8_lossy(&data).into_owned(),
+		}
+	}
+
+	pub fn hide_keyboard(&mut self) {
+		unsafe { self.inner.as_mut().HideKeyboard() }
+	}
+
+	// ---- Input ----
+
+	/// Sets how the overlay receives laser pointer input. With
+	/// [`OverlayInputMethod::Mouse`], pointing at the overlay generates mouse
+	/// events, see [`Self::poll_next_event()`].
+	///
+	/// Outside of the dashboard, the laser pointer only appears if the overlay is
+	/// also made interactive with [`Self::set_interactive_if_visible()`].
+	pub fn set_input_method(
+		&mut self,
+		overlay: OverlayHandle,
+		method: OverlayInputMethod,
+	) -> Result<(), EVROverlayError> {
+		let err = unsafe {
+			self.inner
+				.as_mut()
+				.SetOverlayInputMethod(overlay.0, method.into())
+		};
+		EVROverlayError::new(err)
+	}
+
+	/// Sets the size of the overlay's mouse coordinate space. Mouse events report
+	/// positions from `(0, 0)` to `(width, height)`, so this is usually the size of
+	/// the texture in pixels.
+	pub fn set_mouse_scale(
+		&mut self,
+		overlay: OverlayHandle,
+		width: f32,
+		height: f32,
+	) -> Result<(), EVROverlayError> {
+		let scale = sys::HmdVector2_t { v: [width, height] };
+		let err =
+			unsafe { self.inner.as_mut().SetOverlayMouseScale(overlay.0, &scale) };
+		EVROverlayError::new(err)
+	}
+
+	/// Gets the size of the overlay's mouse coordinate space, as `(width, height)`.
+	pub fn mouse_scale(
+		&mut self,
+		overlay: OverlayHandle,
+	) -> Result<(f32, f32), EVROverlayError> {
+		let mut scale = sys::HmdVector2_t { v: [0.0; 2] };
+		let err = unsafe {
+			self.inner
+				.as_mut()
+				.GetOverlayMouseScale(overlay.0, &mut scale)
+		};
+		EVROverlayError::new(err)?;
+		Ok((scale.v[0], scale.v[1]))
+	}
+
+	// ---- Events ----
+
+	/// Pops the next event for `overlay` off its queue, or returns `None` if it is
+	/// empty.
+	///
+	/// Wraps c++ `PollNextOverlayEvent`.
+	pub fn poll_next_event(&mut self, overlay: OverlayHandle) -> Option<OverlayEvent> {
+		#[cfg(feature = "testing")]
+		if let Some(event) = self.test_events.poll_next_event(overlay) {
+			return Some(event);
+		}
+
+		let mut raw = RawEvent::zeroed();
+		let has_event = unsafe {
+			self.inner.as_mut().PollNextOverlayEvent(
+				overlay.0,
+				(&mut raw as *mut RawEvent).cast(),
+				RawEvent::SIZE,
+			)
+		};
+		has_event.then(|| OverlayEvent::from_raw(&raw))
+	}
+
+	/// Queues `event` for `overlay`, so that it is returned by
+	/// [`Self::poll_next_event()`] before any events from OpenVR. This is meant for
+	/// testing event handling without a headset, and OpenVR never sees the event.
+	#[cfg(feature = "testing")]
+	pub fn inject_test_event(&mut self, overlay: OverlayHandle, event: OverlayEvent) {
+		self.test_events.push(overlay, event);
+	}
+
+	/// Iterates over the events for `overlay` until its queue is drained.
+	pub fn events(&mut self, overlay: OverlayHandle) -> OverlayEvents<'_, 'c> {
+		OverlayEvents {
+			mngr: self,
+			overlay,
+		}
+	}
+
+	/// Casts a ray from `source` along `direction` against the overlay, in the
+	/// tracking space given by `origin`. Returns `None` if the ray misses.
+	///
+	/// Wraps c++ `ComputeOverlayIntersection`.
+	pub fn compute_intersection(
+		&mut self,
+		overlay: OverlayHandle,
+		source: [f32; 3],
+		direction: [f32; 3],
+		origin: TrackingUniverseOrigin,
+	) -> Option<IntersectionResults> {
+		let params = sys::VROverlayIntersectionParams_t {
+			vSource: sys::HmdVector3_t { v: source },
+			vDirection: sys::HmdVector3_t { v: direction },
+			eOrigin: origin,
+		};
+		let mut results: MaybeUninit<sys::VROverlayIntersectionResults_t> =
+			MaybeUninit::uninit();
+		let hit = unsafe {
+			self.inner.as_mut().ComputeOverlayIntersection(
+				overlay.0,
+				&params,
+				results.as_mut_ptr(),
+			)
+		};
+		if !hit {
+			return None;
+		}
+		let results = unsafe { results.assume_init() };
+		Some(IntersectionResults {
+			point: results.vPoint.v,
+			normal: results.vNormal.v,
+			uv: results.vUVs.v,
+			distance: results.fDistance,
+		})
+	}
 }
 unsafe impl Send for OverlayManager<'_> {}
-unsafe impl Sync for OverlayManager<'_> {}
+
+/// How an overlay receives laser pointer input, see
+/// [`OverlayManager::set_input_method()`].
+///
+/// OpenVR no longer supports dual analog input, so there is no variant for it.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum OverlayInputMethod {
+	/// The overlay receives no input.
+	None,
+	/// The laser pointer acts as a mouse.
+	Mouse,
+}
+impl From<OverlayInputMethod> for sys::VROverlayInputMethod {
+	fn from(method: OverlayInputMethod) -> Self {
+		match method {
+			OverlayInputMethod::None => Self::VROverlayInputMethod_None,
+			OverlayInputMethod::Mouse => Self::VROverlayInputMethod_Mouse,
+		}
+	}
+}
+
+/// Options for [`OverlayManager::show_keyboard()`], mirroring c++ `EKeyboardFlags`.
+///
+/// OpenVR takes these as independent bits rather than a single mode, so they are
+/// passed as an `EnumSet` instead of a `KeyboardMode` enum.
+#[derive(EnumSetType, Debug)]
+#[enumset(repr = "u32")]
+pub enum KeyboardFlag {
+	/// Send key events immediately, instead of accumulating the text in a buffer.
+	Minimal,
+	/// Take all focus, and dismiss the keyboard when clicking outside of its panel.
+	Modal,
+	/// Show arrow keys, when in [`Self::Minimal`] mode.
+	ShowArrowKeys,
+	/// Hide the done key.
+	HideDoneKey,
+}
+
+/// The color space of an overlay's texture.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ColorSpace {
+	/// Let OpenVR guess from the texture format.
+	Auto,
+	Gamma,
+	Linear,
+}
+impl From<ColorSpace> for sys::EColorSpace {
+	fn from(space: ColorSpace) -> Self {
+		match space {
+			ColorSpace::Auto => Self::ColorSpace_Auto,
+			ColorSpace::Gamma => Self::ColorSpace_Gamma,
+			ColorSpace::Linear => Self::ColorSpace_Linear,
+		}
+	}
+}
+impl From<sys::EColorSpace> for ColorSpace {
+	fn from(space: sys::EColorSpace) -> Self {
+		match space {
+			sys::EColorSpace::ColorSpace_Auto => Self::Auto,
+			sys::EColorSpace::ColorSpace_Gamma => Self::Gamma,
+			sys::EColorSpace::ColorSpace_Linear => Self::Linear,
+		}
+	}
+}
+
+/// A GPU texture, for [`OverlayManager::set_texture()`].
+///
+/// Only constructible through [`Self::new()`], whose safety contract is what makes
+/// passing it to OpenVR sound.
+#[derive(Into)]
+#[repr(transparent)]
+pub struct Texture(sys::Texture_t);
+impl Texture {
+	/// # Safety
+	/// `handle` must point to a texture of the kind given by `texture_type`, such
+	/// as an `ID3D11Texture2D*` for `TextureType_DirectX`, and must stay valid until
+	/// it has been submitted.
+	pub unsafe fn new(
+		handle: *mut c_void,
+		texture_type: sys::ETextureType,
+		color_space: sys::EColorSpace,
+	) -> Self {
+		Self(sys::Texture_t {
+			handle: handle.cast(),
+			eType: texture_type,
+			eColorSpace: color_space,
+		})
+	}
+}
+
+/// A Vulkan image, for [`OverlayManager::set_texture_vulkan()`].
+///
+/// Mirrors c++ `VRVulkanTextureData_t`. The `Vk*` handles are passed as opaque
+/// pointers so that no particular Vulkan binding is required.
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct VulkanTextureData {
+	/// The `VkImage` handle.
+	pub image: u64,
+	pub device: *mut c_void,
+	pub physical_device: *mut c_void,
+	pub instance: *mut c_void,
+	pub queue: *mut c_void,
+	pub queue_family_index: u32,
+	pub width: u32,
+	pub height: u32,
+	/// The image's `VkFormat`.
+	pub format: u32,
+	pub sample_count: u32,
+}
+
+/// An event from [`OverlayManager::poll_next_event()`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OverlayEvent {
+	/// The raw `sys::EVREventType` of the event.
+	pub event_type: u32,
+	/// The device that caused the event, if any.
+	pub tracked_device_index: TrackedDeviceIndex,
+	/// How long ago the event happened, in seconds.
+	pub event_age_seconds: f32,
+	pub kind: OverlayEventKind,
+}
+
+/// The decoded contents of an [`OverlayEvent`].
+///
+/// Mouse positions are in the overlay's mouse coordinate space, and `button` is
+/// a bitmask of `sys::EVRMouseButton`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub enum OverlayEventKind {
+	MouseMove {
+		x: f32,
+		y: f32,
+		button: u32,
+	},
+	MouseButtonDown {
+		x: f32,
+		y: f32,
+		button: u32,
+	},
+	MouseButtonUp {
+		x: f32,
+		y: f32,
+		button: u32,
+	},
+	/// Scrolling, either in discrete steps or smoothly, depending on which of the
+	/// scroll flags is set on the overlay.
+	Scroll {
+		x_delta: f32,
+		y_delta: f32,
+		smooth: bool,
+	},
+	/// The laser pointer started pointing at `overlay`.
+	FocusEnter {
+		overlay: OverlayHandle,
+	},
+	/// The laser pointer stopped pointing at `overlay`.
+	FocusLeave {
+		overlay: OverlayHandle,
+	},
+	/// The overlay was closed by the user or the dashboard.
+	OverlayClosed,
+	/// An event that has no typed representation yet. Check
+	/// [`OverlayEvent::event_type`] instead.
+	Other,
+}
+
+impl OverlayEvent {
+	fn from_raw(raw: &RawEvent) -> Self {
+		use sys::EVREventType as E;
+		const MOUSE_MOVE: u32 = E::VREvent_MouseMove as u32;
+		const MOUSE_BUTTON_DOWN: u32 = E::VREvent_MouseButtonDown as u32;
+		const MOUSE_BUTTON_UP: u32 = E::VREvent_MouseButtonUp as u32;
+		const SCROLL_DISCRETE: u32 = E::VREvent_ScrollDiscrete as u32;
+		const SCROLL_SMOOTH: u32 = E::VREvent_ScrollSmooth as u32;
+		const FOCUS_ENTER: u32 = E::VREvent_FocusEnter as u32;
+		const FOCUS_LEAVE: u32 = E::VREvent_FocusLeave as u32;
+		const OVERLAY_CLOSED: u32 = E::VREvent_OverlayClosed as u32;
+
+		// Data is `VREvent_Mouse_t`, which starts with x, y and the button.
+		let (x, y, button) = (raw.data_f32(0), raw.data_f32(1), raw.data_u32(2));
+		// Data is `VREvent_Overlay_t`, which starts with the overlay handle.
+		let overlay = OverlayHandle(raw.data_u64(0));
+		let kind = match raw.event_type {
+			MOUSE_MOVE => OverlayEventKind::MouseMove { x, y, button },
+			MOUSE_BUTTON_DOWN => OverlayEventKind::MouseButtonDown { x, y, button },
+			MOUSE_BUTTON_UP => OverlayEventKind::MouseButtonUp { x, y, button },
+			// Data is `VREvent_Scroll_t`, which starts with the x and y deltas.
+			SCROLL_DISCRETE | SCROLL_SMOOTH => OverlayEventKind::Scroll {
+				x_delta: raw.data_f32(0),
+				y_delta: raw.data_f32(1),
+				smooth: raw.event_type == SCROLL_SMOOTH,
+			},
+			FOCUS_ENTER => OverlayEventKind::FocusEnter { overlay },
+			FOCUS_LEAVE => OverlayEventKind::FocusLeave { overlay },
+			OVERLAY_CLOSED => OverlayEventKind::OverlayClosed,
+			_ => OverlayEventKind::Other,
+		};
+		Self {
+			event_type: raw.event_type,
+			tracked_device_index: TrackedDeviceIndex(raw.tracked_device_index),
+			event_age_seconds: raw.event_age_seconds,
+			kind,
+		}
+	}
+}
+
+/// Fake overlay events, which [`OverlayManager::poll_next_event()`] returns
+/// before any events from OpenVR, see [`OverlayManager::inject_test_event()`].
+///
+/// This doesn't need a [`Context`], so event handling can be tested on its own,
+/// such as in CI.
+#[cfg(feature = "testing")]
+#[derive(Debug, Default)]
+pub struct TestEventQueue {
+	events: std::collections::VecDeque<(OverlayHandle, OverlayEvent)>,
+}
+#[cfg(feature = "testing")]
+impl TestEventQueue {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Queues `event` for `overlay`.
+	pub fn push(&mut self, overlay: OverlayHandle, event: OverlayEvent) {
+		self.events.push_back((overlay, event));
+	}
+
+	/// Pops the oldest queued event for `overlay`, leaving the events of other
+	/// overlays in place.
+	pub fn poll_next_event(&mut self, overlay: OverlayHandle) -> Option<OverlayEvent> {
+		let i = self.events.iter().position(|(o, _)| *o == overlay)?;
+		self.events.remove(i).map(|(_, event)| event)
+	}
+}
+
+/// Iterator over the queued events of an overlay, see
+/// [`OverlayManager::events()`].
+pub struct OverlayEvents<'m, 'c> {
+	mngr: &'m mut OverlayManager<'c>,
+	overlay: OverlayHandle,
+}
+impl Iterator for OverlayEvents<'_, '_> {
+	type Item = OverlayEvent;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		self.mngr.poll_next_event(self.overlay)
+	}
+}
+
+/// The calls made by [`OverlayManager::create_overlays()`], so that its rollback
+/// can be tested without OpenVR.
+trait OverlayLifecycle {
+	fn create(
+		&mut self,
+		key: &str,
+		friendly_name: &str,
+	) -> Result<OverlayHandle, EVROverlayError>;
+	fn apply(
+		&mut self,
+		overlay: OverlayHandle,
+		settings: &OverlaySettings,
+	) -> Result<(), EVROverlayError>;
+	fn destroy(&mut self, overlay: OverlayHandle) -> Result<(), EVROverlayError>;
+}
+impl OverlayLifecycle for OverlayManager<'_> {
+	fn create(
+		&mut self,
+		key: &str,
+		friendly_name: &str,
+	) -> Result<OverlayHandle, EVROverlayError> {
+		self.create_overlay(key, friendly_name)
+	}
+
+	fn apply(
+		&mut self,
+		overlay: OverlayHandle,
+		settings: &OverlaySettings,
+	) -> Result<(), EVROverlayError> {
+		self.apply_settings(overlay, settings)
+	}
+
+	fn destroy(&mut self, overlay: OverlayHandle) -> Result<(), EVROverlayError> {
+		self.destroy_overlay(overlay)
+	}
+}
+
+fn create_overlays(
+	mngr: &mut impl OverlayLifecycle,
+	defs: &[(String, String, OverlaySettings)],
+) -> Result<Vec<OverlayHandle>, EVROverlayError> {
+	let mut created = Vec::with_capacity(defs.len());
+	for (key, friendly_name, settings) in defs {
+		let result = mngr.create(key, friendly_name).and_then(|overlay| {
+			created.push(overlay);
+			mngr.apply(overlay, settings)
+		});
+		if let Err(err) = result {
+			for overlay in created {
+				if let Err(destroy_err) = mngr.destroy(overlay) {
+					log::warn!(
+						"failed to roll back overlay {overlay:?}: {destroy_err}"
+					);
+				}
+			}
+			return Err(err);
+		}
+	}
+	Ok(created)
+}
+
+/// The curvature at which an overlay of `width` wraps around a cylinder of
+/// `radius`, clamped to `[0,1]`.
+fn curvature_for_radius(width: f32, radius: f32) -> f32 {
+	(width / (2. * std::f32::consts::PI * radius)).clamp(0., 1.)
+}
+
+fn validate_opacity(alpha: f32) -> Result<(), EVROverlayError> {
+	if (0.0..=1.0).contains(&alpha) {
+		Ok(())
+	} else {
+		EVROverlayError::new(sys::EVROverlayError::VROverlayError_InvalidParameter)
+	}
+}
+
+fn validate_curvature(curvature: f32) -> Result<(), EVROverlayError> {
+	if (0.0..=1.0).contains(&curvature) {
+		Ok(())
+	} else {
+		EVROverlayError::new(sys::EVROverlayError::VROverlayError_InvalidParameter)
+	}
+}
+
+/// Converts a string for passing to OpenVR, returning
+/// `VROverlayError_InvalidParameter` if it contains a nul byte.
+fn to_cstring(s: impl Into<Vec<u8>>) -> Result<CString, EVROverlayError> {
+	if let Ok(s) = CString::new(s) {
+		Ok(s)
+	} else {
+		Err(
+			EVROverlayError::new(sys::EVROverlayError::VROverlayError_InvalidParameter)
+				.unwrap_err(),
+		)
+	}
+}
+
+/// Converts an overlay key and friendly name for passing to OpenVR.
+fn to_cstrings(
+	key: &str,
+	friendly_name: &str,
+) -> Result<(CString, CString), EVROverlayError> {
+	Ok((to_cstring(key)?, to_cstring(friendly_name)?))
+}
+
+/// Flags that change how an overlay is displayed and which events it receives.
+///
+/// The discriminants are the bit positions used by OpenVR's `VROverlayFlags`.
+#[derive(EnumSetType, Debug)]
+#[enumset(repr = "u32")]
+pub enum OverlayFlag {
+	NoDashboardTab = 3,
+	SendVRDiscreteScrollEvents = 6,
+	SendVRTouchpadEvents = 7,
+	ShowTouchPadScrollWheel = 8,
+	TransferOwnershipToInternalProcess = 9,
+	SideBySideParallel = 10,
+	SideBySideCrossed = 11,
+	Panorama = 12,
+	StereoPanorama = 13,
+	SortWithNonSceneOverlays = 14,
+	VisibleInDashboard = 15,
+	MakeOverlaysInteractiveIfVisible = 16,
+	SendVRSmoothScrollEvents = 17,
+	ProtectedContent = 18,
+	HideLaserIntersection = 19,
+	WantsModalBehavior = 20,
+	IsPremultiplied = 21,
+}
+impl From<OverlayFlag> for sys::VROverlayFlags {
+	fn from(flag: OverlayFlag) -> Self {
+		use sys::VROverlayFlags as F;
+		match flag {
+			OverlayFlag::NoDashboardTab => F::VROverlayFlags_NoDashboardTab,
+			OverlayFlag::SendVRDiscreteScrollEvents => {
+				F::VROverlayFlags_SendVRDiscreteScrollEvents
+			}
+			OverlayFlag::SendVRTouchpadEvents => F::VROverlayFlags_SendVRTouchpadEvents,
+			OverlayFlag::ShowTouchPadScrollWheel => {
+				F::VROverlayFlags_ShowTouchPadScrollWheel
+			}
+			OverlayFlag::TransferOwnershipToInternalProcess => {
+				F::VROverlayFlags_TransferOwnershipToInternalProcess
+			}
+			OverlayFlag::SideBySideParallel => F::VROverlayFlags_SideBySide_Parallel,
+			OverlayFlag::SideBySideCrossed => F::VROverlayFlags_SideBySide_Crossed,
+			OverlayFlag::Panorama => F::VROverlayFlags_Panorama,
+			OverlayFlag::StereoPanorama => F::VROverlayFlags_StereoPanorama,
+			OverlayFlag::SortWithNonSceneOverlays => {
+				F::VROverlayFlags_SortWithNonSceneOverlays
+			}
+			OverlayFlag::VisibleInDashboard => F::VROverlayFlags_VisibleInDashboard,
+			OverlayFlag::MakeOverlaysInteractiveIfVisible => {
+				F::VROverlayFlags_MakeOverlaysInteractiveIfVisible
+			}
+			OverlayFlag::SendVRSmoothScrollEvents => {
+				F::VROverlayFlags_SendVRSmoothScrollEvents
+			}
+			OverlayFlag::ProtectedContent => F::VROverlayFlags_ProtectedContent,
+			OverlayFlag::HideLaserIntersection => {
+				F::VROverlayFlags_HideLaserIntersection
+			}
+			OverlayFlag::WantsModalBehavior => F::VROverlayFlags_WantsModalBehavior,
+			OverlayFlag::IsPremultiplied => F::VROverlayFlags_IsPremultiplied,
+		}
+	}
+}
+
+/// Where a ray hit an overlay, see [`OverlayManager::compute_intersection()`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IntersectionResults {
+	/// The point of intersection, in tracking space.
+	pub point: [f32; 3],
+	/// The overlay's surface normal at `point`.
+	pub normal: [f32; 3],
+	/// Texture coordinates of `point` on the overlay.
+	pub uv: [f32; 2],
+	/// Distance from the ray's source to `point`, in meters.
+	pub distance: f32,
+}
+
+#[cfg(feature = "glam")]
+impl IntersectionResults {
+	pub fn point(&self) -> glam::Vec3 {
+		glam::Vec3::from_array(self.point)
+	}
+
+	pub fn normal(&self) -> glam::Vec3 {
+		glam::Vec3::from_array(self.normal)
+	}
+
+	pub fn uv(&self) -> glam::Vec2 {
+		glam::Vec2::from_array(self.uv)
+	}
+}
+
+/// The layout of an overlay, see [`OverlayManager::snapshot()`].
+///
+/// With the `serde` feature, this can be serialized to save overlay layouts to
+/// disk.
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OverlaySnapshot {
+	pub width: f32,
+	pub curvature: f32,
+	/// Color and opacity of the overlay. The opacity is stored as the alpha.
+	pub tint: ColorTint,
+	pub sort_order: u32,
+	#[cfg_attr(feature = "serde", serde(with = "origin_serde"))]
+	pub origin: TrackingUniverseOrigin,
+	pub transform: Matrix3x4,
+}
+
+/// Stores a [`TrackingUniverseOrigin`] by name, as the sys enum has no serde
+/// support.
+#[cfg(feature = "serde")]
+mod origin_serde {
+	use super::TrackingUniverseOrigin;
+
+	use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+	pub fn serialize<S: Serializer>(
+		origin: &TrackingUniverseOrigin,
+		serializer: S,
+	) -> Result<S::Ok, S::Error> {
+		let name = match origin {
+			TrackingUniverseOrigin::TrackingUniverseSeated => "Seated",
+			TrackingUniverseOrigin::TrackingUniverseStanding => "Standing",
+			TrackingUniverseOrigin::TrackingUniverseRawAndUncalibrated => {
+				"RawAndUncalibrated"
+			}
+		};
+		name.serialize(serializer)
+	}
+
+	pub fn deserialize<'de, D: Deserializer<'de>>(
+		deserializer: D,
+	) -> Result<TrackingUniverseOrigin, D::Error> {
+		let name = String::deserialize(deserializer)?;
+		match name.as_str() {
+			"Seated" => Ok(TrackingUniverseOrigin::TrackingUniverseSeated),
+			"Standing" => Ok(TrackingUniverseOrigin::TrackingUniverseStanding),
+			"RawAndUncalibrated" => {
+				Ok(TrackingUniverseOrigin::TrackingUniverseRawAndUncalibrated)
+			}
+			other => Err(D::Error::unknown_variant(
+				other,
+				&["Seated", "Standing", "RawAndUncalibrated"],
+			)),
+		}
+	}
+}
 
 #[derive(From, Debug, PartialEq, Eq, Clone, Copy)]
 pub struct OverlayHandle(pub sys::VROverlayHandle_t);
+
+/// Settings to apply to an overlay with [`OverlayManager::apply_settings()`].
+/// Settings that are `None` are left unchanged.
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct OverlaySettings {
+	/// See [`OverlayManager::set_width()`].
+	pub width: Option<f32>,
+	/// See [`OverlayManager::set_curvature()`].
+	pub curvature: Option<f32>,
+	/// See [`OverlayManager::set_tint()`].
+	pub tint: Option<ColorTint>,
+	/// See [`OverlayManager::set_opacity()`]. Applied after `tint`, so this takes
+	/// precedence over the tint's alpha. Values outside of `[0,1]` are rejected
+	/// with `VROverlayError_InvalidParameter` instead of panicking.
+	pub opacity: Option<f32>,
+}
+
+/// Chainable settings for an existing overlay, created by
+/// [`OverlayManager::configure()`].
+#[must_use = "settings are only applied when calling `apply()`"]
+pub struct OverlayConfig<'m, 'c> {
+	mngr: &'m mut OverlayManager<'c>,
+	overlay: OverlayHandle,
+	settings: OverlaySettings,
+}
+impl OverlayConfig<'_, '_> {
+	/// See [`OverlayManager::set_width()`].
+	pub fn width(mut self, width_in_meters: f32) -> Self {
+		self.settings.width = Some(width_in_meters);
+		self
+	}
+
+	/// See [`OverlayManager::set_curvature()`].
+	pub fn curvature(mut self, curvature: f32) -> Self {
+		self.settings.curvature = Some(curvature);
+		self
+	}
+
+	/// See [`OverlayManager::set_tint()`].
+	pub fn tint(mut self, tint: ColorTint) -> Self {
+		self.settings.tint = Some(tint);
+		self
+	}
+
+	/// See [`OverlayManager::set_opacity()`]. Applied after [`Self::tint()`], so
+	/// this takes precedence over the tint's alpha. Values outside of `[0,1]` make
+	/// [`Self::apply()`] return `VROverlayError_InvalidParameter`.
+	pub fn opacity(mut self, alpha: f32) -> Self {
+		self.settings.opacity = Some(alpha);
+		self
+	}
+
+	/// Applies every requested setting. Settings are all attempted even if an
+	/// earlier one fails, and the first error encountered is returned.
+	pub fn apply(self) -> Result<(), EVROverlayError> {
+		self.mngr.apply_settings(self.overlay, &self.settings)
+	}
+}
+
+/// An overlay that is destroyed when dropped, created by
+/// [`OverlayManager::create_overlay_scoped()`].
+///
+/// Dereferences to its [`OverlayHandle`], which is used with the manager as usual.
+/// It only borrows the [`Context`], so any number of scoped overlays can be alive
+/// at once.
+///
+/// `Drop` can't return errors, so if destroying the overlay fails, the error is
+/// logged as a warning and otherwise ignored. Use [`Self::destroy()`] to handle
+/// the error instead.
+pub struct ScopedOverlay<'c> {
+	ctx: PhantomData<&'c Context>,
+	overlay: OverlayHandle,
+}
+impl<'c> ScopedOverlay<'c> {
+	/// Destroys the overlay now, returning any error instead of logging it.
+	pub fn destroy(self, mngr: &mut OverlayManager<'c>) -> Result<(), EVROverlayError> {
+		let overlay = self.overlay;
+		// Destroying it is up to us now.
+		std::mem::forget(self);
+		mngr.destroy_overlay(overlay)
+	}
+}
+impl std::ops::Deref for ScopedOverlay<'_> {
+	type Target = OverlayHandle;
+
+	fn deref(&self) -> &Self::Target {
+		&self.overlay
+	}
+}
+impl Drop for ScopedOverlay<'_> {
+	fn drop(&mut self) {
+		let mut mngr = OverlayManager::for_context(self.ctx);
+		if let Err(err) = mngr.destroy_overlay(self.overlay) {
+			log::warn!("failed to destroy scoped overlay: {err}");
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn overlay_flags_match_sys_bits() {
+		for flag in EnumSet::<OverlayFlag>::all() {
+			let sys_flag: sys::VROverlayFlags = flag.into();
+			assert_eq!(sys_flag as u32, EnumSet::only(flag).as_repr(), "{flag:?}");
+		}
+	}
+
+	#[test]
+	fn curvature_for_radius_wraps_width() {
+		use std::f32::consts::PI;
+
+		// Wrapping around a quarter of the circle.
+		assert!((curvature_for_radius(PI / 2., 1.) - 0.25).abs() < 1e-6);
+		assert_eq!(curvature_for_radius(1., f32::INFINITY), 0.);
+		// Too tight to wrap, so it is clamped to a closed cylinder.
+		assert_eq!(curvature_for_radius(10., 0.1), 1.);
+	}
+
+	#[test]
+	fn curvature_range() {
+		assert_eq!(validate_curvature(0.0), Ok(()));
+		assert_eq!(validate_curvature(1.0), Ok(()));
+		for invalid in [1.5, -0.1, f32::NAN] {
+			assert_eq!(
+				validate_curvature(invalid).map_err(|err| err.inner()),
+				Err(sys::EVROverlayError::VROverlayError_InvalidParameter),
+				"{invalid}"
+			);
+		}
+	}
+
+	#[test]
+	fn create_overlays_rolls_back_on_invalid_settings() {
+		/// Only validates settings, and records which overlays exist.
+		#[derive(Default)]
+		struct Fake {
+			alive: Vec<OverlayHandle>,
+			next: u64,
+		}
+		impl OverlayLifecycle for Fake {
+			fn create(
+				&mut self,
+				_key: &str,
+				_friendly_name: &str,
+			) -> Result<OverlayHandle, EVROverlayError> {
+				self.next += 1;
+				self.alive.push(OverlayHandle(self.next));
+				Ok(OverlayHandle(self.next))
+			}
+
+			fn apply(
+				&mut self,
+				_overlay: OverlayHandle,
+				settings: &OverlaySettings,
+			) -> Result<(), EVROverlayError> {
+				settings.opacity.map_or(Ok(()), validate_opacity)
+			}
+
+			fn destroy(
+				&mut self,
+				overlay: OverlayHandle,
+			) -> Result<(), EVROverlayError> {
+				self.alive.retain(|o| *o != overlay);
+				Ok(())
+			}
+		}
+
+		let def = |key: &str, opacity| {
+			let settings = OverlaySettings {
+				opacity: Some(opacity),
+				..Default::default()
+			};
+			(key.to_owned(), key.to_owned(), settings)
+		};
+
+		let mut fake = Fake::default();
+		let created = create_overlays(&mut fake, &[def("a", 0.5), def("b", 1.0)]);
+		assert_eq!(created, Ok(vec![OverlayHandle(1), OverlayHandle(2)]));
+
+		// The invalid opacity of the last overlay undoes the whole batch, including
+		// the overlay whose settings failed.
+		let mut fake = Fake::default();
+		let defs = [def("a", 0.5), def("b", 1.0), def("c", 2.0)];
+		assert_eq!(
+			create_overlays(&mut fake, &defs).map_err(|err| err.inner()),
+			Err(sys::EVROverlayError::VROverlayError_InvalidParameter)
+		);
+		assert_eq!(fake.alive, []);
+	}
+
+	#[test]
+	fn opacity_range() {
+		assert_eq!(validate_opacity(0.0), Ok(()));
+		assert_eq!(validate_opacity(1.0), Ok(()));
+		for invalid in [1.5, -0.1, f32::NAN] {
+			assert_eq!(
+				validate_opacity(invalid).map_err(|err| err.inner()),
+				Err(sys::EVROverlayError::VROverlayError_InvalidParameter),
+				"{invalid}"
+			);
+		}
+	}
+
+	#[test]
+	fn decode_mouse_event() {
+		fn word(lo: [u8; 4], hi: [u8; 4]) -> u64 {
+			let mut bytes = [0u8; 8];
+			bytes[..4].copy_from_slice(&lo);
+			bytes[4..].copy_from_slice(&hi);
+			u64::from_ne_bytes(bytes)
+		}
+		let mut raw = RawEvent::zeroed();
+		raw.event_type = sys::EVREventType::VREvent_MouseButtonDown as u32;
+		raw.data[0] = word(0.25f32.to_ne_bytes(), 0.75f32.to_ne_bytes());
+		raw.data[1] = word(1u32.to_ne_bytes(), [0; 4]);
+
+		assert_eq!(
+			OverlayEvent::from_raw(&raw).kind,
+			OverlayEventKind::MouseButtonDown {
+				x: 0.25,
+				y: 0.75,
+				button: 1,
+			}
+		);
+	}
+
+	#[test]
+	#[ignore = "requires a running SteamVR instance"]
+	fn ray_hits_center_of_overlay() {
+		crate::tests::with_context(|ctx| {
+			let mut mngr = ctx.overlay_mngr();
+			let overlay = mngr
+				.create_overlay("ovr_overlay.tests.intersection", "Intersection")
+				.unwrap();
+			// One meter ahead, facing back towards the origin.
+			let origin_to_overlay = Matrix3x4([
+				[1.0, 0.0, 0.0, 0.0],
+				[0.0, 1.0, 0.0, 0.0],
+				[0.0, 0.0, 1.0, -1.0],
+			]);
+			let origin = TrackingUniverseOrigin::TrackingUniverseStanding;
+			mngr.set_width(overlay, 1.0).unwrap();
+			mngr.set_transform_absolute(overlay, origin, &origin_to_overlay)
+				.unwrap();
+
+			let hit = mngr
+				.compute_intersection(
+					overlay,
+					[0.0, 0.0, 0.0],
+					[0.0, 0.0, -1.0],
+					origin,
+				)
+				.expect("ray should hit the overlay");
+			assert!((hit.uv[0] - 0.5).abs() < 1e-3, "{hit:?}");
+			assert!((hit.uv[1] - 0.5).abs() < 1e-3, "{hit:?}");
+			assert!((hit.distance - 1.0).abs() < 1e-3, "{hit:?}");
+
+			let miss = mngr.compute_intersection(
+				overlay,
+				[0.0, 0.0, 0.0],
+				[0.0, 0.0, 1.0],
+				origin,
+			);
+			assert_eq!(miss, None);
+
+			mngr.destroy_overlay(overlay).unwrap();
+		});
+	}
+
+	#[test]
+	#[ignore = "requires a running SteamVR instance"]
+	fn clearing_texture_keeps_overlay_visible() {
+		crate::tests::with_context(|ctx| {
+			let mut mngr = ctx.overlay_mngr();
+			let overlay = mngr
+				.create_overlay("ovr_overlay.tests.clear_texture", "Clear Texture")
+				.unwrap();
+			mngr.set_raw_data(overlay, &[255; 4 * 4 * 4], 4, 4, 4)
+				.unwrap();
+			mngr.set_visibility(overlay, true).unwrap();
+
+			mngr.clear_texture(overlay).unwrap();
+			assert!(mngr.is_visible(overlay));
+
+			mngr.destroy_overlay(overlay).unwrap();
+		});
+	}
+
+	#[test]
+	#[ignore = "requires a running SteamVR instance"]
+	fn scoped_overlay_is_destroyed_on_drop() {
+		crate::tests::with_context(|ctx| {
+			let mut mngr = ctx.overlay_mngr();
+			let key = "ovr_overlay.tests.scoped";
+			let other_key = "ovr_overlay.tests.scoped_other";
+			{
+				let scoped = mngr.create_overlay_scoped(key, "Scoped").unwrap();
+				let other = mngr.create_overlay_scoped(other_key, "Other").unwrap();
+				mngr.set_width(*scoped, 1.0).unwrap();
+				assert_eq!(mngr.find_overlay(key), Ok(*scoped));
+				other.destroy(&mut mngr).unwrap();
+				assert!(mngr.find_overlay(other_key).is_err());
+			}
+			assert_eq!(
+				mngr.find_overlay(key).map_err(|err| err.inner()),
+				Err(sys::EVROverlayError::VROverlayError_UnknownOverlay)
+			);
+		});
+	}
+
+	#[test]
+	#[ignore = "requires a running SteamVR instance"]
+	fn snapshot_restores_layout() {
+		crate::tests::with_context(|ctx| {
+			let mut mngr = ctx.overlay_mngr();
+			let scoped = mngr
+				.create_overlay_scoped("ovr_overlay.tests.snapshot", "Snapshot")
+				.unwrap();
+			let overlay = *scoped;
+			let transform =
+				Matrix3x4::from_translation_rotation([0., 1., -2.], [1., 0., 0., 0.]);
+			mngr.set_transform_absolute(
+				overlay,
+				TrackingUniverseOrigin::TrackingUniverseStanding,
+				&transform,
+			)
+			.unwrap();
+			mngr.set_width(overlay, 0.5).unwrap();
+			let snapshot = mngr.snapshot(overlay).unwrap();
+
+			mngr.set_width(overlay, 2.0).unwrap();
+			mngr.set_sort_order(overlay, snapshot.sort_order + 1)
+				.unwrap();
+			mngr.restore(overlay, &snapshot).unwrap();
+			assert_eq!(mngr.snapshot(overlay).unwrap(), snapshot);
+		});
+	}
+
+	#[test]
+	#[ignore = "requires a running SteamVR instance"]
+	fn create_overlays_rolls_back_on_error() {
+		crate::tests::with_context(|ctx| {
+			let mut mngr = ctx.overlay_mngr();
+			let key = "ovr_overlay.tests.batch";
+			let settings = OverlaySettings {
+				width: Some(0.5),
+				..Default::default()
+			};
+			// The second overlay reuses the key of the first, so it fails to be created.
+			let defs = [
+				(key.to_owned(), "Batch".to_owned(), settings),
+				(key.to_owned(), "Duplicate".to_owned(), settings),
+			];
+			assert_eq!(
+				mngr.create_overlays(&defs).map_err(|err| err.inner()),
+				Err(sys::EVROverlayError::VROverlayError_KeyInUse)
+			);
+			assert!(mngr.find_overlay(key).is_err());
+		});
+	}
+
+	#[cfg(feature = "testing")]
+	#[test]
+	fn test_events_are_polled_in_order() {
+		let event = |kind| OverlayEvent {
+			event_type: 0,
+			tracked_device_index: TrackedDeviceIndex::HMD,
+			event_age_seconds: 0.,
+			kind,
+		};
+		let overlay = OverlayHandle(1);
+		let other = OverlayHandle(2);
+		let mut queue = TestEventQueue::new();
+		queue.push(other, event(OverlayEventKind::OverlayClosed));
+		queue.push(overlay, event(OverlayEventKind::FocusEnter { overlay }));
+		queue.push(overlay, event(OverlayEventKind::FocusLeave { overlay }));
+
+		let kinds: Vec<_> = std::iter::from_fn(|| queue.poll_next_event(overlay))
+			.map(|e| e.kind)
+			.collect();
+		assert_eq!(
+			kinds,
+			[
+				OverlayEventKind::FocusEnter { overlay },
+				OverlayEventKind::FocusLeave { overlay },
+			]
+		);
+		// Events of other overlays are left alone.
+		assert_eq!(
+			queue.poll_next_event(other).map(|e| e.kind),
+			Some(OverlayEventKind::OverlayClosed)
+		);
+		assert_eq!(queue.poll_next_event(other), None);
+	}
+
+	#[cfg(feature = "testing")]
+	#[test]
+	#[ignore = "requires a running SteamVR instance"]
+	fn injected_events_are_polled_in_order() {
+		crate::tests::with_context(|ctx| {
+			let mut mngr = ctx.overlay_mngr();
+			let scoped = mngr
+				.create_overlay_scoped("ovr_overlay.tests.inject", "Inject")
+				.unwrap();
+			let overlay = *scoped;
+			let event = |kind| OverlayEvent {
+				event_type: 0,
+				tracked_device_index: TrackedDeviceIndex::HMD,
+				event_age_seconds: 0.,
+				kind,
+			};
+			let other = OverlayHandle(overlay.0 + 1);
+			mngr.inject_test_event(other, event(OverlayEventKind::OverlayClosed));
+			mngr.inject_test_event(
+				overlay,
+				event(OverlayEventKind::FocusEnter { overlay }),
+			);
+			mngr.inject_test_event(
+				overlay,
+				event(OverlayEventKind::FocusLeave { overlay }),
+			);
+
+			let kinds: Vec<_> = mngr.events(overlay).map(|e| e.kind).collect();
+			assert_eq!(
+				kinds,
+				[
+					OverlayEventKind::FocusEnter { overlay },
+					OverlayEventKind::FocusLeave { overlay },
+				]
+			);
+		});
+	}
+
+	#[test]
+	#[ignore = "requires a running SteamVR instance"]
+	fn premultiplied_alpha_sets_flag() {
+		crate::tests::with_context(|ctx| {
+			let mut mngr = ctx.overlay_mngr();
+			let scoped = mngr
+				.create_overlay_scoped(
+					"ovr_overlay.tests.premultiplied",
+					"Premultiplied",
+				)
+				.unwrap();
+			let overlay = *scoped;
+
+			mngr.set_premultiplied_alpha(overlay, true).unwrap();
+			assert!(mngr
+				.flags(overlay)
+				.unwrap()
+				.contains(OverlayFlag::IsPremultiplied));
+			mngr.set_premultiplied_alpha(overlay, false).unwrap();
+			assert!(!mngr
+				.flags(overlay)
+				.unwrap()
+				.contains(OverlayFlag::IsPremultiplied));
+		});
+	}
+
+	#[test]
+	#[ignore = "requires a running SteamVR instance"]
+	fn getters_reject_destroyed_handle() {
+		crate::tests::with_context(|ctx| {
+			let mut mngr = ctx.overlay_mngr();
+			let overlay = mngr
+				.create_overlay("ovr_overlay.tests.destroyed", "Destroyed")
+				.unwrap();
+			mngr.destroy_overlay(overlay).unwrap();
+
+			let invalid_handle =
+				Some(sys::EVROverlayError::VROverlayError_InvalidHandle);
+			assert_eq!(mngr.width(overlay).err().map(|e| e.inner()), invalid_handle);
+			assert_eq!(
+				mngr.curvature(overlay).err().map(|e| e.inner()),
+				invalid_handle
+			);
+			assert_eq!(
+				mngr.opacity(overlay).err().map(|e| e.inner()),
+				invalid_handle
+			);
+			assert_eq!(
+				mngr.visibility(overlay).err().map(|e| e.inner()),
+				invalid_handle
+			);
+		});
+	}
+}
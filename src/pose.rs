@@ -1,9 +1,206 @@
 use crate::sys;
 
+use derive_more::{From, Into};
+
 /// Row-major 3x4 matrix
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
 pub struct Matrix3x4(pub [[f32; 4]; 3]);
+impl Matrix3x4 {
+	pub const fn from_rows(rows: [[f32; 4]; 3]) -> Self {
+		Self(rows)
+	}
+
+	pub const fn into_rows(self) -> [[f32; 4]; 3] {
+		self.0
+	}
+
+	/// Inverts the matrix, treating it as an affine transform whose left 3x3 block
+	/// is the basis and whose last column is the translation.
+	///
+	/// If the basis is singular (not invertible), the result contains non-finite
+	/// values.
+	pub fn inverse_affine(&self) -> Matrix3x4 {
+		let m = &self.0;
+		// Cofactors of the 3x3 basis, which form the transposed adjugate.
+		let cofactor = |r0: usize, r1: usize, c0: usize, c1: usize| {
+			m[r0][c0] * m[r1][c1] - m[r0][c1] * m[r1][c0]
+		};
+		let adjugate = [
+			[
+				cofactor(1, 2, 1, 2),
+				-cofactor(0, 2, 1, 2),
+				cofactor(0, 1, 1, 2),
+			],
+			[
+				-cofactor(1, 2, 0, 2),
+				cofactor(0, 2, 0, 2),
+				-cofactor(0, 1, 0, 2),
+			],
+			[
+				cofactor(1, 2, 0, 1),
+				-cofactor(0, 2, 0, 1),
+				cofactor(0, 1, 0, 1),
+			],
+		];
+		let det = m[0][0] * adjugate[0][0]
+			+ m[0][1] * adjugate[1][0]
+			+ m[0][2] * adjugate[2][0];
+		let inv_det = 1. / det;
+
+		let mut result = [[0.; 4]; 3];
+		for row in 0..3 {
+			for col in 0..3 {
+				result[row][col] = adjugate[row][col] * inv_det;
+			}
+			// The inverse translation is the original one, mapped through the inverse
+			// basis and negated.
+			result[row][3] = -(0..3).map(|i| result[row][i] * m[i][3]).sum::<f32>();
+		}
+		Matrix3x4(result)
+	}
+
+	/// Builds a rigid transform from a translation and a unit rotation quaternion
+	/// given as `[w, x, y, z]`.
+	pub fn from_translation_rotation(translation: [f32; 3], quat: [f32; 4]) -> Self {
+		let [w, x, y, z] = quat;
+		let [tx, ty, tz] = translation;
+		Matrix3x4([
+			[
+				1. - 2. * (y * y + z * z),
+				2. * (x * y - w * z),
+				2. * (x * z + w * y),
+				tx,
+			],
+			[
+				2. * (x * y + w * z),
+				1. - 2. * (x * x + z * z),
+				2. * (y * z - w * x),
+				ty,
+			],
+			[
+				2. * (x * z - w * y),
+				2. * (y * z + w * x),
+				1. - 2. * (x * x + y * y),
+				tz,
+			],
+		])
+	}
+
+	/// Builds a transform located at `eye` that looks at `target`, with `up`
+	/// pointing roughly upwards. Like OpenVR's devices, it looks along its -Z axis,
+	/// with +Y up.
+	///
+	/// If `up` is parallel to the view direction, or `eye` equals `target`, the
+	/// result contains non-finite values.
+	pub fn look_at(eye: [f32; 3], target: [f32; 3], up: [f32; 3]) -> Self {
+		Self::from_z_axis(eye, vec3::sub(eye, target), up)
+	}
+
+	/// Builds a transform for an overlay at `position` that faces `camera`, staying
+	/// upright. This is the usual transform for HUDs and name plates, and can be
+	/// passed to [`crate::overlay::OverlayManager::set_transform_absolute()`].
+	///
+	/// If `camera` is directly above or below `position`, the result contains
+	/// non-finite values.
+	pub fn billboard_toward(position: [f32; 3], camera: [f32; 3]) -> Self {
+		// Overlays are seen from their +Z side, so that side has to face the camera.
+		Self::from_z_axis(position, vec3::sub(camera, position), [0., 1., 0.])
+	}
+
+	fn from_z_axis(translation: [f32; 3], z: [f32; 3], up: [f32; 3]) -> Self {
+		let z = vec3::normalize(z);
+		let x = vec3::normalize(vec3::cross(up, z));
+		let y = vec3::cross(z, x);
+		let [tx, ty, tz] = translation;
+		Matrix3x4([
+			[x[0], y[0], z[0], tx],
+			[x[1], y[1], z[1], ty],
+			[x[2], y[2], z[2], tz],
+		])
+	}
+
+	/// The translation, which is the last column.
+	pub fn translation(&self) -> [f32; 3] {
+		[self.0[0][3], self.0[1][3], self.0[2][3]]
+	}
+
+	/// Extracts the rotation of the left 3x3 block as a unit quaternion
+	/// `[w, x, y, z]`. The block must be a pure rotation, without scale.
+	pub fn rotation_quaternion(&self) -> [f32; 4] {
+		let m = &self.0;
+		let trace = m[0][0] + m[1][1] + m[2][2];
+		// Branch on the largest diagonal term, to avoid dividing by a small number.
+		if trace > 0. {
+			let s = (trace + 1.).sqrt() * 2.;
+			[
+				s / 4.,
+				(m[2][1] - m[1][2]) / s,
+				(m[0][2] - m[2][0]) / s,
+				(m[1][0] - m[0][1]) / s,
+			]
+		} else if m[0][0] > m[1][1] && m[0][0] > m[2][2] {
+			let s = (1. + m[0][0] - m[1][1] - m[2][2]).sqrt() * 2.;
+			[
+				(m[2][1] - m[1][2]) / s,
+				s / 4.,
+				(m[0][1] + m[1][0]) / s,
+				(m[0][2] + m[2][0]) / s,
+			]
+		} else if m[1][1] > m[2][2] {
+			let s = (1. + m[1][1] - m[0][0] - m[2][2]).sqrt() * 2.;
+			[
+				(m[0][2] - m[2][0]) / s,
+				(m[0][1] + m[1][0]) / s,
+				s / 4.,
+				(m[1][2] + m[2][1]) / s,
+			]
+		} else {
+			let s = (1. + m[2][2] - m[0][0] - m[1][1]).sqrt() * 2.;
+			[
+				(m[1][0] - m[0][1]) / s,
+				(m[0][2] + m[2][0]) / s,
+				(m[1][2] + m[2][1]) / s,
+				s / 4.,
+			]
+		}
+	}
+
+	/// Transposes the left 3x3 block (the basis), leaving the translation as-is.
+	///
+	/// For a pure rotation, the transposed basis is also its inverse.
+	pub fn transpose_basis(&self) -> Matrix3x4 {
+		let mut result = *self;
+		for row in 0..3 {
+			for col in 0..3 {
+				result.0[row][col] = self.0[col][row];
+			}
+		}
+		result
+	}
+}
+
+/// Small helpers for the `[x, y, z]` arrays used by [`Matrix3x4`].
+mod vec3 {
+	pub fn sub(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+		[a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+	}
+
+	pub fn cross(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+		[
+			a[1] * b[2] - a[2] * b[1],
+			a[2] * b[0] - a[0] * b[2],
+			a[0] * b[1] - a[1] * b[0],
+		]
+	}
+
+	pub fn normalize(v: [f32; 3]) -> [f32; 3] {
+		let len = v.iter().map(|c| c * c).sum::<f32>().sqrt();
+		v.map(|c| c / len)
+	}
+}
+
 impl From<&Matrix3x4> for &sys::HmdMatrix34_t {
 	fn from(other: &Matrix3x4) -> Self {
 		let other = other as *const Matrix3x4;
@@ -38,13 +235,120 @@ impl From<&mut sys::HmdMatrix34_t> for &mut Matrix3x4 {
 }
 impl From<sys::HmdMatrix34_t> for Matrix3x4 {
 	fn from(other: sys::HmdMatrix34_t) -> Self {
-		// Get shrekt, autocxx 😎
-		unsafe { std::mem::transmute(other) }
+		Self(other.m)
 	}
 }
 impl From<Matrix3x4> for sys::HmdMatrix34_t {
 	fn from(other: Matrix3x4) -> Self {
-		unsafe { std::mem::transmute(other) }
+		Self { m: other.0 }
+	}
+}
+// The reference casts above rely on the layouts being identical.
+const _: () = assert!(
+	std::mem::size_of::<Matrix3x4>() == std::mem::size_of::<sys::HmdMatrix34_t>()
+		&& std::mem::align_of::<Matrix3x4>()
+			== std::mem::align_of::<sys::HmdMatrix34_t>()
+);
+
+/// Fills the matrix from a flat, row-major array.
+impl From<[f32; 12]> for Matrix3x4 {
+	fn from(flat: [f32; 12]) -> Self {
+		Self(std::array::from_fn(|row| {
+			std::array::from_fn(|col| flat[row * 4 + col])
+		}))
+	}
+}
+/// Fills the matrix from a flat, row-major slice, which must have exactly 12 elements.
+impl TryFrom<&[f32]> for Matrix3x4 {
+	type Error = std::array::TryFromSliceError;
+
+	fn try_from(flat: &[f32]) -> Result<Self, Self::Error> {
+		let flat: [f32; 12] = flat.try_into()?;
+		Ok(flat.into())
+	}
+}
+
+/// Row-major 4x4 matrix, such as a projection matrix.
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(C)]
+pub struct Matrix4x4(pub [[f32; 4]; 4]);
+
+impl From<&Matrix4x4> for &sys::HmdMatrix44_t {
+	fn from(other: &Matrix4x4) -> Self {
+		let other = other as *const Matrix4x4;
+		// safety: C++ POD types have same memory layout as order of fields
+		//   so it is safe to cast it this way
+		unsafe { &*other.cast() }
+	}
+}
+impl From<&sys::HmdMatrix44_t> for &Matrix4x4 {
+	fn from(other: &sys::HmdMatrix44_t) -> Self {
+		let other = other as *const sys::HmdMatrix44_t;
+		// safety: C++ POD types have same memory layout as order of fields
+		//   so it is safe to cast it this way
+		unsafe { &*other.cast() }
+	}
+}
+impl From<&mut Matrix4x4> for &mut sys::HmdMatrix44_t {
+	fn from(other: &mut Matrix4x4) -> Self {
+		let other = other as *mut Matrix4x4;
+		// safety: C++ POD types have same memory layout as order of fields
+		//   so it is safe to cast it this way
+		unsafe { &mut *other.cast() }
+	}
+}
+impl From<&mut sys::HmdMatrix44_t> for &mut Matrix4x4 {
+	fn from(other: &mut sys::HmdMatrix44_t) -> Self {
+		let other = other as *mut sys::HmdMatrix44_t;
+		// safety: C++ POD types have same memory layout as order of fields
+		//   so it is safe to cast it this way
+		unsafe { &mut *other.cast() }
+	}
+}
+impl From<sys::HmdMatrix44_t> for Matrix4x4 {
+	fn from(other: sys::HmdMatrix44_t) -> Self {
+		Self(other.m)
+	}
+}
+impl From<Matrix4x4> for sys::HmdMatrix44_t {
+	fn from(other: Matrix4x4) -> Self {
+		Self { m: other.0 }
+	}
+}
+// The reference casts above rely on the layouts being identical.
+const _: () = assert!(
+	std::mem::size_of::<Matrix4x4>() == std::mem::size_of::<sys::HmdMatrix44_t>()
+		&& std::mem::align_of::<Matrix4x4>()
+			== std::mem::align_of::<sys::HmdMatrix44_t>()
+);
+
+/// A 3d vector, such as a position or direction.
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(C)]
+pub struct Vector3(pub [f32; 3]);
+impl From<sys::HmdVector3_t> for Vector3 {
+	fn from(other: sys::HmdVector3_t) -> Self {
+		Self(other.v)
+	}
+}
+impl From<Vector3> for sys::HmdVector3_t {
+	fn from(other: Vector3) -> Self {
+		Self { v: other.0 }
+	}
+}
+
+/// A 4d vector.
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(C)]
+pub struct Vector4(pub [f32; 4]);
+impl From<sys::HmdVector4_t> for Vector4 {
+	fn from(other: sys::HmdVector4_t) -> Self {
+		Self(other.v)
+	}
+}
+impl From<Vector4> for sys::HmdVector4_t {
+	fn from(other: Vector4) -> Self {
+		Self { v: other.0 }
 	}
 }
 
@@ -86,10 +390,133 @@ mod nalgebra_impls {
 			Self(other.transpose().data.0)
 		}
 	}
+
+	impl From<&Matrix4x4> for nalgebra::Matrix4<f32> {
+		fn from(other: &Matrix4x4) -> Self {
+			use slice_of_array::SliceFlatExt;
+			Self::from_row_slice(other.0.flat())
+		}
+	}
+
+	impl<Storage>
+		From<
+			&nalgebra::Matrix<
+				f32,
+				nalgebra::base::dimension::U4,
+				nalgebra::base::dimension::U4,
+				Storage,
+			>,
+		> for Matrix4x4
+	where
+		Storage: nalgebra::base::storage::Storage<
+			f32,
+			nalgebra::base::dimension::U4,
+			nalgebra::base::dimension::U4,
+		>,
+	{
+		fn from(
+			other: &nalgebra::Matrix<
+				f32,
+				nalgebra::base::dimension::U4,
+				nalgebra::base::dimension::U4,
+				Storage,
+			>,
+		) -> Self {
+			Self(other.transpose().data.0)
+		}
+	}
+}
+
+#[cfg(feature = "glam")]
+mod glam_impls {
+	use super::*;
+
+	/// Pads the matrix to 4x4, with `[0, 0, 0, 1]` as the last row.
+	impl From<&Matrix3x4> for glam::Mat4 {
+		fn from(other: &Matrix3x4) -> Self {
+			let [r0, r1, r2] = other.0;
+			// glam is column-major, so build the transpose and flip it back.
+			Self::from_cols_array_2d(&[r0, r1, r2, [0., 0., 0., 1.]]).transpose()
+		}
+	}
+
+	impl From<glam::Affine3A> for Matrix3x4 {
+		fn from(other: glam::Affine3A) -> Self {
+			let m = other.matrix3;
+			let t = other.translation;
+			Self(std::array::from_fn(|row| {
+				[m.x_axis[row], m.y_axis[row], m.z_axis[row], t[row]]
+			}))
+		}
+	}
+}
+
+pub use sys::ETrackingResult as TrackingResult;
+/// One of the eyes of the HMD.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Eye {
+	Left,
+	Right,
+}
+impl Eye {
+	/// Iterates over both eyes, left first.
+	pub fn iter() -> impl Iterator<Item = Eye> {
+		[Eye::Left, Eye::Right].into_iter()
+	}
+}
+impl From<Eye> for sys::EVREye {
+	fn from(eye: Eye) -> Self {
+		match eye {
+			Eye::Left => Self::Eye_Left,
+			Eye::Right => Self::Eye_Right,
+		}
+	}
+}
+impl From<sys::EVREye> for Eye {
+	fn from(eye: sys::EVREye) -> Self {
+		match eye {
+			sys::EVREye::Eye_Left => Self::Left,
+			sys::EVREye::Eye_Right => Self::Right,
+		}
+	}
 }
 
 pub use sys::ETrackingUniverseOrigin as TrackingUniverseOrigin;
 
+/// The pose of a tracked device, as reported by OpenVR.
+#[derive(From, Into)]
+#[repr(transparent)]
+pub struct TrackedDevicePose(pub sys::TrackedDevicePose_t);
+impl TrackedDevicePose {
+	/// Transform from the device to the tracking universe it was queried in.
+	pub fn device_to_absolute_tracking(&self) -> Matrix3x4 {
+		*<&Matrix3x4>::from(&self.0.mDeviceToAbsoluteTracking)
+	}
+
+	/// How well the device is currently being tracked.
+	pub fn tracking_result(&self) -> TrackingResult {
+		self.0.eTrackingResult
+	}
+
+	pub fn pose_is_valid(&self) -> bool {
+		self.0.bPoseIsValid
+	}
+
+	pub fn device_is_connected(&self) -> bool {
+		self.0.bDeviceIsConnected
+	}
+
+	/// Velocity in the tracking universe, in meters per second.
+	pub fn velocity(&self) -> [f32; 3] {
+		self.0.vVelocity.v
+	}
+
+	/// Angular velocity in the tracking universe, in radians per second.
+	pub fn angular_velocity(&self) -> [f32; 3] {
+		self.0.vAngularVelocity.v
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	#[allow(unused)]
@@ -115,6 +542,149 @@ mod tests {
         [3.,  7.,  11.],
     ];
 
+	#[test]
+	fn test_from_flat() {
+		use slice_of_array::SliceFlatExt;
+
+		let flat: [f32; 12] = DATA_ROW.flat().try_into().unwrap();
+		assert_eq!(Matrix3x4::from(flat), Matrix3x4(DATA_ROW));
+		assert_eq!(Matrix3x4::try_from(&flat[..]).unwrap(), Matrix3x4(DATA_ROW));
+		assert!(Matrix3x4::try_from(&flat[..11]).is_err());
+		assert!(Matrix3x4::try_from(&[0.; 13][..]).is_err());
+	}
+
+	#[test]
+	fn test_sys_conversions_agree() {
+		let m = Matrix3x4::from_rows(DATA_ROW);
+		let by_value = sys::HmdMatrix34_t::from(m);
+		let by_ref: &sys::HmdMatrix34_t = (&m).into();
+		assert_eq!(by_value.m, by_ref.m);
+		assert_eq!(Matrix3x4::from(by_value), *<&Matrix3x4>::from(by_ref));
+		assert_eq!(m.into_rows(), DATA_ROW);
+	}
+
+	/// Applies `m` as an affine transform to `p`.
+	fn transform_point(m: &Matrix3x4, p: [f32; 3]) -> [f32; 3] {
+		std::array::from_fn(|row| {
+			(0..3).map(|i| m.0[row][i] * p[i]).sum::<f32>() + m.0[row][3]
+		})
+	}
+
+	fn assert_approx_eq(a: [f32; 3], b: [f32; 3]) {
+		for (a, b) in a.into_iter().zip(b) {
+			assert!((a - b).abs() < 1e-5, "{a} != {b}");
+		}
+	}
+
+	#[test]
+	fn test_inverse_affine() {
+		#[rustfmt::skip]
+		let rigid = Matrix3x4([
+			// 90 degree rotation around y, then translation
+			[0.,  0., 1., 1.],
+			[0.,  1., 0., 2.],
+			[-1., 0., 0., 3.],
+		]);
+		#[rustfmt::skip]
+		let scaled = Matrix3x4([
+			[2., 1., 0., -1.],
+			[0., 3., 0., 0.5],
+			[0., 0., 0.5, 4.],
+		]);
+
+		for m in [rigid, scaled] {
+			let inv = m.inverse_affine();
+			for p in [[0., 0., 0.], [1., -2., 3.], [0.5, 0.25, -4.]] {
+				assert_approx_eq(transform_point(&inv, transform_point(&m, p)), p);
+				assert_approx_eq(transform_point(&m, transform_point(&inv, p)), p);
+			}
+		}
+
+		// For rigid transforms, the inverse basis is the transposed basis.
+		let inv = rigid.inverse_affine();
+		let transposed = rigid.transpose_basis();
+		for row in 0..3 {
+			assert_approx_eq(
+				[inv.0[row][0], inv.0[row][1], inv.0[row][2]],
+				[
+					transposed.0[row][0],
+					transposed.0[row][1],
+					transposed.0[row][2],
+				],
+			);
+		}
+	}
+
+	#[test]
+	fn test_translation_rotation_roundtrip() {
+		let half = std::f32::consts::FRAC_1_SQRT_2;
+		let quats = [
+			[1., 0., 0., 0.],
+			// 90 degrees around y
+			[half, 0., half, 0.],
+			// 180 degrees around x, where w is 0
+			[0., 1., 0., 0.],
+			[0.5, 0.5, -0.5, 0.5],
+		];
+		for quat in quats {
+			let m = Matrix3x4::from_translation_rotation([1., 2., 3.], quat);
+			assert_eq!(m.translation(), [1., 2., 3.]);
+
+			let q = m.rotation_quaternion();
+			// `q` and `-q` are the same rotation.
+			let dot: f32 = q.iter().zip(quat).map(|(a, b)| a * b).sum();
+			let sign = dot.signum();
+			for (a, b) in q.into_iter().zip(quat) {
+				assert!((a * sign - b).abs() < 1e-5, "{q:?} != {quat:?}");
+			}
+		}
+
+		// Rotating 90 degrees around y maps +x to -z.
+		let m = Matrix3x4::from_translation_rotation([0.; 3], [half, 0., half, 0.]);
+		assert_approx_eq(transform_point(&m, [1., 0., 0.]), [0., 0., -1.]);
+	}
+
+	#[test]
+	fn test_look_at_and_billboard() {
+		let up = [0., 1., 0.];
+		// Looking down -Z from the origin is the identity.
+		let m = Matrix3x4::look_at([0.; 3], [0., 0., -5.], up);
+		assert_eq!(
+			m,
+			Matrix3x4::from_translation_rotation([0.; 3], [1., 0., 0., 0.])
+		);
+
+		let eye = [1., 2., 3.];
+		let target = [4., -2., 3.];
+		let m = Matrix3x4::look_at(eye, target, up);
+		assert_eq!(m.translation(), eye);
+		// The target is 5 units along -Z.
+		assert_approx_eq(transform_point(&m, [0., 0., -5.]), target);
+		// The basis stays orthonormal, so it is a valid overlay transform.
+		let inv = m.inverse_affine();
+		let transposed = m.transpose_basis();
+		for row in 0..3 {
+			assert_approx_eq(
+				[inv.0[row][0], inv.0[row][1], inv.0[row][2]],
+				[
+					transposed.0[row][0],
+					transposed.0[row][1],
+					transposed.0[row][2],
+				],
+			);
+		}
+
+		// The overlay's front (+Z) points at the camera and its +Y stays upright.
+		let position = [0., 1.5, -2.];
+		let camera = [2., 1.5, -2.];
+		let m = Matrix3x4::billboard_toward(position, camera);
+		assert_approx_eq(transform_point(&m, [0., 0., 2.]), camera);
+		assert_approx_eq(
+			transform_point(&m, [0., 1., 0.]),
+			[position[0], position[1] + 1., position[2]],
+		);
+	}
+
 	/// A series of sanity checks to ensure that matrix math, and nalgebra, work as expected
 	#[cfg(feature = "nalgebra")]
 	#[test]
@@ -161,4 +731,40 @@ mod tests {
 		assert_eq!(m_ovr, Matrix3x4::from(&m_nalg));
 		assert_eq!(m_nalg, nalgebra::Matrix3x4::from(&m_ovr))
 	}
+
+	#[cfg(feature = "glam")]
+	#[test]
+	fn test_glam_conversion() {
+		let affine = glam::Affine3A::from_rotation_translation(
+			glam::Quat::from_rotation_y(1.),
+			glam::Vec3::new(1., 2., 3.),
+		);
+		let m_ovr = Matrix3x4::from(affine);
+		let m_glam = glam::Mat4::from(&m_ovr);
+		assert_eq!(m_glam, glam::Mat4::from(affine));
+
+		let p = glam::Vec3::new(0.5, -1., 2.);
+		assert_approx_eq(
+			transform_point(&m_ovr, p.to_array()),
+			affine.transform_point3(p).to_array(),
+		);
+	}
+
+	#[cfg(feature = "nalgebra")]
+	#[test]
+	fn test_nalgebra_conversion_4x4() {
+		use slice_of_array::SliceFlatExt;
+
+		#[rustfmt::skip]
+		let data = [
+			[0.,  1.,  2.,  3. ],
+			[4.,  5.,  6.,  7. ],
+			[8.,  9.,  10., 11.],
+			[12., 13., 14., 15.],
+		];
+		let m_nalg = nalgebra::Matrix4::from_row_slice(data.flat());
+		let m_ovr = Matrix4x4(data);
+		assert_eq!(m_ovr, Matrix4x4::from(&m_nalg));
+		assert_eq!(m_nalg, nalgebra::Matrix4::from(&m_ovr))
+	}
 }
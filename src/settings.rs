@@ -0,0 +1,168 @@
+pub use crate::errors::EVRSettingsError;
+use crate::{sys, Context};
+
+use std::ffi::CStr;
+use std::marker::PhantomData;
+use std::pin::Pin;
+
+pub struct SettingsManager<'c> {
+	ctx: PhantomData<&'c Context>,
+	inner: Pin<&'c mut sys::IVRSettings>,
+}
+
+type Result<T> = std::result::Result<T, EVRSettingsError>;
+
+impl<'c> SettingsManager<'c> {
+	pub(super) fn new(_ctx: &'c Context) -> Self {
+		let inner =
+			unsafe { Pin::new_unchecked(sys::VRSettings().as_mut::<'c>().unwrap()) };
+		Self {
+			ctx: Default::default(),
+			inner,
+		}
+	}
+
+	/// Typed accessors for the `steamvr` section.
+	pub fn steamvr(&mut self) -> steamvr::SteamVrSettings<'_, 'c> {
+		steamvr::SteamVrSettings { mngr: self }
+	}
+
+	pub fn get_bool(&mut self, section: &CStr, key: &CStr) -> Result<bool> {
+		let mut err = sys::EVRSettingsError::VRSettingsError_None;
+		let value = unsafe {
+			self.inner
+				.as_mut()
+				.GetBool(section.as_ptr(), key.as_ptr(), &mut err)
+		};
+		EVRSettingsError::new(err)?;
+		Ok(value)
+	}
+
+	pub fn set_bool(&mut self, section: &CStr, key: &CStr, value: bool) -> Result<()> {
+		let mut err = sys::EVRSettingsError::VRSettingsError_None;
+		unsafe {
+			self.inner
+				.as_mut()
+				.SetBool(section.as_ptr(), key.as_ptr(), value, &mut err)
+		};
+		EVRSettingsError::new(err)
+	}
+
+	pub fn get_int32(&mut self, section: &CStr, key: &CStr) -> Result<i32> {
+		let mut err = sys::EVRSettingsError::VRSettingsError_None;
+		let value = unsafe {
+			self.inner
+				.as_mut()
+				.GetInt32(section.as_ptr(), key.as_ptr(), &mut err)
+		};
+		EVRSettingsError::new(err)?;
+		Ok(value)
+	}
+
+	pub fn set_int32(&mut self, section: &CStr, key: &CStr, value: i32) -> Result<()> {
+		let mut err = sys::EVRSettingsError::VRSettingsError_None;
+		unsafe {
+			self.inner.as_mut().SetInt32(
+				section.as_ptr(),
+				key.as_ptr(),
+				value,
+				&mut err,
+			)
+		};
+		EVRSettingsError::new(err)
+	}
+
+	pub fn get_float(&mut self, section: &CStr, key: &CStr) -> Result<f32> {
+		let mut err = sys::EVRSettingsError::VRSettingsError_None;
+		let value = unsafe {
+			self.inner
+				.as_mut()
+				.GetFloat(section.as_ptr(), key.as_ptr(), &mut err)
+		};
+		EVRSettingsError::new(err)?;
+		Ok(value)
+	}
+
+	pub fn set_float(&mut self, section: &CStr, key: &CStr, value: f32) -> Result<()> {
+		let mut err = sys::EVRSettingsError::VRSettingsError_None;
+		unsafe {
+			self.inner.as_mut().SetFloat(
+				section.as_ptr(),
+				key.as_ptr(),
+				value,
+				&mut err,
+			)
+		};
+		EVRSettingsError::new(err)
+	}
+}
+unsafe impl Send for SettingsManager<'_> {}
+
+/// Typed access to the `steamvr` settings section, so that section and key names
+/// can't be mistyped.
+pub mod steamvr {
+	use super::{Result, SettingsManager};
+
+	use std::ffi::CStr;
+
+	fn cstr(bytes: &'static [u8]) -> &'static CStr {
+		CStr::from_bytes_with_nul(bytes).unwrap()
+	}
+
+	fn section() -> &'static CStr {
+		cstr(b"steamvr\0")
+	}
+
+	/// Created by [`SettingsManager::steamvr()`].
+	pub struct SteamVrSettings<'m, 'c> {
+		pub(super) mngr: &'m mut SettingsManager<'c>,
+	}
+
+	impl SteamVrSettings<'_, '_> {
+		/// Whether motion smoothing is enabled.
+		pub fn motion_smoothing(&mut self) -> Result<bool> {
+			self.mngr.get_bool(section(), cstr(b"motionSmoothing\0"))
+		}
+
+		pub fn set_motion_smoothing(&mut self, enabled: bool) -> Result<()> {
+			self.mngr
+				.set_bool(section(), cstr(b"motionSmoothing\0"), enabled)
+		}
+
+		/// Whether [`Self::supersample_scale()`] is used instead of the automatic
+		/// resolution.
+		pub fn supersample_manual_override(&mut self) -> Result<bool> {
+			self.mngr
+				.get_bool(section(), cstr(b"supersampleManualOverride\0"))
+		}
+
+		pub fn set_supersample_manual_override(&mut self, enabled: bool) -> Result<()> {
+			self.mngr
+				.set_bool(section(), cstr(b"supersampleManualOverride\0"), enabled)
+		}
+
+		/// The resolution scale, only in effect when
+		/// [`Self::supersample_manual_override()`] is enabled.
+		pub fn supersample_scale(&mut self) -> Result<f32> {
+			self.mngr.get_float(section(), cstr(b"supersampleScale\0"))
+		}
+
+		pub fn set_supersample_scale(&mut self, scale: f32) -> Result<()> {
+			self.mngr
+				.set_float(section(), cstr(b"supersampleScale\0"), scale)
+		}
+
+		pub fn render_target_multiplier(&mut self) -> Result<f32> {
+			self.mngr
+				.get_float(section(), cstr(b"renderTargetMultiplier\0"))
+		}
+
+		pub fn set_render_target_multiplier(&mut self, multiplier: f32) -> Result<()> {
+			self.mngr.set_float(
+				section(),
+				cstr(b"renderTargetMultiplier\0"),
+				multiplier,
+			)
+		}
+	}
+}
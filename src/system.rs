@@ -1,8 +1,14 @@
 use crate::errors::ETrackedPropertyError;
+use crate::event::RawEvent;
+use crate::pose::{
+	Eye, Matrix3x4, Matrix4x4, TrackedDevicePose, TrackingUniverseOrigin,
+};
 use crate::{sys, Context, TrackedDeviceIndex};
 
+use derive_more::{From, Into};
 use std::ffi::{CStr, CString};
 use std::marker::PhantomData;
+use std::mem::MaybeUninit;
 use std::pin::Pin;
 use std::ptr::null_mut;
 
@@ -12,6 +18,245 @@ pub struct SystemManager<'c> {
 	string_buf: Box<[u8]>,
 }
 
+/// Legacy (non action-based) button and axis state of a controller.
+#[derive(From, Into)]
+#[repr(transparent)]
+pub struct ControllerState(pub sys::VRControllerState001_t);
+impl ControllerState {
+	/// Incremented whenever the state changes. If this is unchanged, so is the
+	/// rest of the state.
+	pub fn packet_num(&self) -> u32 {
+		self.0.unPacketNum
+	}
+
+	/// Bitmask of pressed buttons, indexed by `sys::EVRButtonId`.
+	pub fn button_pressed(&self) -> u64 {
+		self.0.ulButtonPressed
+	}
+
+	/// Bitmask of touched buttons, indexed by `sys::EVRButtonId`.
+	pub fn button_touched(&self) -> u64 {
+		self.0.ulButtonTouched
+	}
+
+	/// The `(x, y)` values of each of the controller's axes.
+	pub fn axes(&self) -> [(f32, f32); 5] {
+		std::array::from_fn(|i| (self.0.rAxis[i].x, self.0.rAxis[i].y))
+	}
+}
+
+/// The kind of a tracked device.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum TrackedDeviceClass {
+	/// There is no device at this index.
+	Invalid,
+	Hmd,
+	Controller,
+	/// A tracker without buttons, such as a Vive Tracker.
+	GenericTracker,
+	/// A base station or camera that other devices are tracked relative to.
+	TrackingReference,
+	/// An accessory that displays the HMD output on another screen.
+	DisplayRedirect,
+}
+impl From<TrackedDeviceClass> for sys::ETrackedDeviceClass {
+	fn from(class: TrackedDeviceClass) -> Self {
+		match class {
+			TrackedDeviceClass::Invalid => Self::TrackedDeviceClass_Invalid,
+			TrackedDeviceClass::Hmd => Self::TrackedDeviceClass_HMD,
+			TrackedDeviceClass::Controller => Self::TrackedDeviceClass_Controller,
+			TrackedDeviceClass::GenericTracker => {
+				Self::TrackedDeviceClass_GenericTracker
+			}
+			TrackedDeviceClass::TrackingReference => {
+				Self::TrackedDeviceClass_TrackingReference
+			}
+			TrackedDeviceClass::DisplayRedirect => {
+				Self::TrackedDeviceClass_DisplayRedirect
+			}
+		}
+	}
+}
+impl From<sys::ETrackedDeviceClass> for TrackedDeviceClass {
+	fn from(class: sys::ETrackedDeviceClass) -> Self {
+		use sys::ETrackedDeviceClass::*;
+		match class {
+			TrackedDeviceClass_HMD => Self::Hmd,
+			TrackedDeviceClass_Controller => Self::Controller,
+			TrackedDeviceClass_GenericTracker => Self::GenericTracker,
+			TrackedDeviceClass_TrackingReference => Self::TrackingReference,
+			TrackedDeviceClass_DisplayRedirect => Self::DisplayRedirect,
+			TrackedDeviceClass_Invalid | TrackedDeviceClass_Max => Self::Invalid,
+		}
+	}
+}
+
+/// The role of a controller, such as which hand it is held in.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ControllerRole {
+	/// The device has no role, or isn't a controller.
+	Invalid,
+	LeftHand,
+	RightHand,
+	/// The controller is tracked, but shouldn't be used as a hand.
+	OptOut,
+	Treadmill,
+	Stylus,
+}
+impl ControllerRole {
+	/// Whether this is [`Self::LeftHand`] or [`Self::RightHand`].
+	pub fn is_hand(self) -> bool {
+		matches!(self, Self::LeftHand | Self::RightHand)
+	}
+}
+impl From<ControllerRole> for sys::ETrackedControllerRole {
+	fn from(role: ControllerRole) -> Self {
+		match role {
+			ControllerRole::Invalid => Self::TrackedControllerRole_Invalid,
+			ControllerRole::LeftHand => Self::TrackedControllerRole_LeftHand,
+			ControllerRole::RightHand => Self::TrackedControllerRole_RightHand,
+			ControllerRole::OptOut => Self::TrackedControllerRole_OptOut,
+			ControllerRole::Treadmill => Self::TrackedControllerRole_Treadmill,
+			ControllerRole::Stylus => Self::TrackedControllerRole_Stylus,
+		}
+	}
+}
+impl From<sys::ETrackedControllerRole> for ControllerRole {
+	fn from(role: sys::ETrackedControllerRole) -> Self {
+		use sys::ETrackedControllerRole::*;
+		match role {
+			TrackedControllerRole_Invalid => Self::Invalid,
+			TrackedControllerRole_LeftHand => Self::LeftHand,
+			TrackedControllerRole_RightHand => Self::RightHand,
+			TrackedControllerRole_OptOut => Self::OptOut,
+			TrackedControllerRole_Treadmill => Self::Treadmill,
+			TrackedControllerRole_Stylus => Self::Stylus,
+		}
+	}
+}
+
+/// Whether a device is being used, see [`SystemManager::get_device_activity_level()`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum DeviceActivityLevel {
+	Unknown,
+	/// No activity for the last 10 seconds.
+	Idle,
+	/// Activity, such as movement or the HMD being worn, is happening now.
+	UserInteraction,
+	/// No activity for the last 0.5 seconds.
+	UserInteractionTimeout,
+	/// The device is in standby, for example because the HMD was taken off.
+	Standby,
+	/// No activity for so long that the device is about to go into standby.
+	IdleTimeout,
+}
+impl From<sys::EDeviceActivityLevel> for DeviceActivityLevel {
+	fn from(level: sys::EDeviceActivityLevel) -> Self {
+		use sys::EDeviceActivityLevel::*;
+		match level {
+			k_EDeviceActivityLevel_Unknown => Self::Unknown,
+			k_EDeviceActivityLevel_Idle => Self::Idle,
+			k_EDeviceActivityLevel_UserInteraction => Self::UserInteraction,
+			k_EDeviceActivityLevel_UserInteraction_Timeout => {
+				Self::UserInteractionTimeout
+			}
+			k_EDeviceActivityLevel_Standby => Self::Standby,
+			k_EDeviceActivityLevel_Idle_Timeout => Self::IdleTimeout,
+		}
+	}
+}
+
+/// An event from [`SystemManager::poll_next_event()`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SystemEvent {
+	/// The raw `sys::EVREventType` of the event.
+	pub event_type: u32,
+	/// The device that the event is about, if any.
+	pub tracked_device_index: TrackedDeviceIndex,
+	/// How long ago the event happened, in seconds.
+	pub event_age_seconds: f32,
+	pub kind: SystemEventKind,
+}
+
+/// The decoded contents of a [`SystemEvent`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SystemEventKind {
+	/// SteamVR is shutting down. Call [`SystemManager::acknowledge_quit_exiting()`]
+	/// and exit.
+	Quit,
+	/// The device in [`SystemEvent::tracked_device_index`] was connected.
+	TrackedDeviceActivated,
+	/// The device in [`SystemEvent::tracked_device_index`] was disconnected.
+	TrackedDeviceDeactivated,
+	/// The chaperone universe, and with it the play area, changed.
+	ChaperoneUniverseHasChanged {
+		previous_universe: u64,
+		current_universe: u64,
+	},
+	/// A screenshot requested through `IVRScreenshots` was written to disk.
+	ScreenshotTaken { handle: u32 },
+	/// A screenshot requested through `IVRScreenshots` could not be taken.
+	ScreenshotFailed { handle: u32 },
+	/// An event that has no typed representation yet. Check
+	/// [`SystemEvent::event_type`] instead.
+	Other,
+}
+
+impl SystemEvent {
+	fn from_raw(raw: &RawEvent) -> Self {
+		const SCREENSHOT_TAKEN: u32 = sys::EVREventType::VREvent_ScreenshotTaken as u32;
+		const SCREENSHOT_FAILED: u32 =
+			sys::EVREventType::VREvent_ScreenshotFailed as u32;
+
+		const QUIT: u32 = sys::EVREventType::VREvent_Quit as u32;
+		const TRACKED_DEVICE_ACTIVATED: u32 =
+			sys::EVREventType::VREvent_TrackedDeviceActivated as u32;
+		const TRACKED_DEVICE_DEACTIVATED: u32 =
+			sys::EVREventType::VREvent_TrackedDeviceDeactivated as u32;
+		const CHAPERONE_UNIVERSE_HAS_CHANGED: u32 =
+			sys::EVREventType::VREvent_ChaperoneUniverseHasChanged as u32;
+
+		let kind = match raw.event_type {
+			QUIT => SystemEventKind::Quit,
+			TRACKED_DEVICE_ACTIVATED => SystemEventKind::TrackedDeviceActivated,
+			TRACKED_DEVICE_DEACTIVATED => SystemEventKind::TrackedDeviceDeactivated,
+			// Data is `VREvent_Chaperone_t`.
+			CHAPERONE_UNIVERSE_HAS_CHANGED => {
+				SystemEventKind::ChaperoneUniverseHasChanged {
+					previous_universe: raw.data_u64(0),
+					current_universe: raw.data_u64(1),
+				}
+			}
+			// Data is `VREvent_Screenshot_t`, which starts with the handle.
+			SCREENSHOT_TAKEN => SystemEventKind::ScreenshotTaken {
+				handle: raw.data_u32(0),
+			},
+			SCREENSHOT_FAILED => SystemEventKind::ScreenshotFailed {
+				handle: raw.data_u32(0),
+			},
+			_ => SystemEventKind::Other,
+		};
+		Self {
+			event_type: raw.event_type,
+			tracked_device_index: TrackedDeviceIndex(raw.tracked_device_index),
+			event_age_seconds: raw.event_age_seconds,
+			kind,
+		}
+	}
+}
+
+/// The identity strings of a device, see [`SystemManager::device_info()`].
+///
+/// Properties that the device doesn't provide (yet) are left empty.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DeviceInfo {
+	pub serial_number: String,
+	pub model_number: String,
+	pub manufacturer_name: String,
+	pub render_model_name: String,
+}
+
 mod private {
 	pub trait Sealed {}
 }
@@ -130,7 +375,191 @@ impl<'ret> TrackedDeviceProperty<'ret> for &'ret CStr {
 	}
 }
 
-// TODO: arrays. I don't feel like dealing with them right now.
+impl private::Sealed for crate::pose::Vector3 {}
+impl private::Sealed for crate::pose::Vector4 {}
+
+/// Trait implemented by the element types of array properties.
+///
+/// OpenVR has no getter for single vector properties, so vector-typed properties
+/// such as [`props::Vector4Array::CameraWhiteBalance`] are only available as
+/// arrays of [`crate::pose::Vector3`] or [`crate::pose::Vector4`].
+pub trait TrackedDevicePropertyArray: private::Sealed + Sized {
+	fn get(
+		index: TrackedDeviceIndex,
+		system: &mut SystemManager,
+		prop: sys::ETrackedDeviceProperty,
+	) -> PropResult<Vec<Self>>;
+}
+
+macro_rules! impl_property_array_type {
+	($ty:ty, $sys_ty:ty, $tag:expr) => {
+		impl TrackedDevicePropertyArray for $ty {
+			fn get(
+				index: TrackedDeviceIndex,
+				system: &mut SystemManager,
+				prop: sys::ETrackedDeviceProperty,
+			) -> PropResult<Vec<Self>> {
+				const ELEM_SIZE: usize = std::mem::size_of::<$sys_ty>();
+
+				// Probe for the size in bytes. This reports `BufferTooSmall` unless the
+				// array is empty.
+				let mut err = sys::ETrackedPropertyError::TrackedProp_Success;
+				let len = unsafe {
+					system.inner.as_mut().GetArrayTrackedDeviceProperty(
+						index.0,
+						prop,
+						$tag,
+						null_mut(),
+						0,
+						&mut err,
+					)
+				};
+				if err != sys::ETrackedPropertyError::TrackedProp_BufferTooSmall {
+					ETrackedPropertyError::new(err)?;
+				}
+
+				let count = len as usize / ELEM_SIZE;
+				let mut data: Vec<MaybeUninit<$sys_ty>> =
+					(0..count).map(|_| MaybeUninit::uninit()).collect();
+				let len = unsafe {
+					system.inner.as_mut().GetArrayTrackedDeviceProperty(
+						index.0,
+						prop,
+						$tag,
+						data.as_mut_ptr().cast(),
+						(count * ELEM_SIZE) as u32,
+						&mut err,
+					)
+				};
+				ETrackedPropertyError::new(err)?;
+
+				// Only keep the elements that were actually written.
+				data.truncate(len as usize / ELEM_SIZE);
+				Ok(data
+					.into_iter()
+					.map(|elem| unsafe { elem.assume_init() }.into())
+					.collect())
+			}
+		}
+	};
+}
+
+impl_property_array_type!(f32, f32, sys::k_unFloatPropertyTag);
+impl_property_array_type!(i32, i32, sys::k_unInt32PropertyTag);
+impl_property_array_type!(
+	crate::pose::Matrix3x4,
+	sys::HmdMatrix34_t,
+	sys::k_unHmdMatrix34PropertyTag
+);
+impl_property_array_type!(
+	crate::pose::Vector3,
+	sys::HmdVector3_t,
+	sys::k_unHmdVector3PropertyTag
+);
+impl_property_array_type!(
+	crate::pose::Vector4,
+	sys::HmdVector4_t,
+	sys::k_unHmdVector4PropertyTag
+);
+
+/// Typed subsets of `sys::ETrackedDeviceProperty`, grouped by the type of the
+/// property.
+pub mod props {
+	use crate::sys;
+
+	/// Properties that are arrays of `f32`.
+	#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+	pub enum FloatArray {
+		CameraDistortionCoefficients,
+		DisplayAvailableFrameRates,
+	}
+	impl From<FloatArray> for sys::ETrackedDeviceProperty {
+		fn from(other: FloatArray) -> Self {
+			use sys::ETrackedDeviceProperty::*;
+			match other {
+				FloatArray::CameraDistortionCoefficients => {
+					Prop_CameraDistortionCoefficients_Float_Array
+				}
+				FloatArray::DisplayAvailableFrameRates => {
+					Prop_DisplayAvailableFrameRates_Float_Array
+				}
+			}
+		}
+	}
+
+	/// Properties that are arrays of `i32`.
+	#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+	pub enum Int32Array {
+		CameraDistortionFunction,
+	}
+	impl From<Int32Array> for sys::ETrackedDeviceProperty {
+		fn from(other: Int32Array) -> Self {
+			use sys::ETrackedDeviceProperty::*;
+			match other {
+				Int32Array::CameraDistortionFunction => {
+					Prop_CameraDistortionFunction_Int32_Array
+				}
+			}
+		}
+	}
+
+	/// Properties that are arrays of [`crate::pose::Vector4`].
+	#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+	pub enum Vector4Array {
+		/// White balance of each camera, as rgba multipliers.
+		CameraWhiteBalance,
+	}
+	impl From<Vector4Array> for sys::ETrackedDeviceProperty {
+		fn from(other: Vector4Array) -> Self {
+			use sys::ETrackedDeviceProperty::*;
+			match other {
+				Vector4Array::CameraWhiteBalance => {
+					Prop_CameraWhiteBalance_Vector4_Array
+				}
+			}
+		}
+	}
+
+	/// Properties that are arrays of [`crate::pose::Matrix3x4`].
+	#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+	pub enum Matrix34Array {
+		CameraToHeadTransforms,
+	}
+	impl From<Matrix34Array> for sys::ETrackedDeviceProperty {
+		fn from(other: Matrix34Array) -> Self {
+			use sys::ETrackedDeviceProperty::*;
+			match other {
+				Matrix34Array::CameraToHeadTransforms => {
+					Prop_CameraToHeadTransforms_Matrix34_Array
+				}
+			}
+		}
+	}
+}
+
+/// See [`SystemManager::predicted_seconds_to_photons()`].
+fn seconds_to_photons(
+	frequency: f32,
+	since_vsync: f32,
+	vsync_to_photons: f32,
+) -> Option<f32> {
+	if frequency.is_nan() || frequency <= 0. {
+		return None;
+	}
+	Some(1. / frequency - since_vsync + vsync_to_photons)
+}
+
+fn not_yet_available_as_none<T>(result: PropResult<T>) -> PropResult<Option<T>> {
+	match result {
+		Ok(value) => Ok(Some(value)),
+		Err(e)
+			if e.inner() == sys::ETrackedPropertyError::TrackedProp_NotYetAvailable =>
+		{
+			Ok(None)
+		}
+		Err(e) => Err(e),
+	}
+}
 
 impl<'c> SystemManager<'c> {
 	pub(super) fn new(_ctx: &'c Context) -> Self {
@@ -155,13 +584,397 @@ impl<'c> SystemManager<'c> {
 	) -> PropResult<T> {
 		T::get(index, self, prop)
 	}
+
+	/// Same as [`Self::get_tracked_device_property()`], but returns `Ok(None)` if the
+	/// property is `TrackedProp_NotYetAvailable`, which is common while a device is
+	/// still starting up. Polling loops can retry on `None` and bail on any error.
+	pub fn try_get_tracked_device_property<
+		'ret,
+		'manager: 'ret,
+		T: TrackedDeviceProperty<'ret>,
+	>(
+		&'manager mut self,
+		index: TrackedDeviceIndex,
+		prop: sys::ETrackedDeviceProperty,
+	) -> PropResult<Option<T>> {
+		not_yet_available_as_none(self.get_tracked_device_property(index, prop))
+	}
+
+	/// Reads an array-valued property, such as one from [`props::FloatArray`].
+	///
+	/// Wraps c++ `GetArrayTrackedDeviceProperty`.
+	pub fn get_tracked_device_property_array<T: TrackedDevicePropertyArray>(
+		&mut self,
+		index: TrackedDeviceIndex,
+		prop: impl Into<sys::ETrackedDeviceProperty>,
+	) -> PropResult<Vec<T>> {
+		T::get(index, self, prop.into())
+	}
+
+	/// Reads the serial number, model number, manufacturer and render model name of
+	/// a device.
+	///
+	/// Properties that are not available, for example because the device is still
+	/// starting up, are left empty instead of failing the whole call.
+	pub fn device_info(&mut self, index: TrackedDeviceIndex) -> PropResult<DeviceInfo> {
+		let mut read = |prop| -> PropResult<String> {
+			match self.get_tracked_device_property::<&CStr>(index, prop) {
+				Ok(s) => Ok(s.to_string_lossy().into_owned()),
+				Err(e) => match e.inner() {
+					sys::ETrackedPropertyError::TrackedProp_NotYetAvailable
+					| sys::ETrackedPropertyError::TrackedProp_UnknownProperty
+					| sys::ETrackedPropertyError::TrackedProp_ValueNotProvidedByDevice => {
+						Ok(String::new())
+					}
+					_ => Err(e),
+				},
+			}
+		};
+		use sys::ETrackedDeviceProperty::*;
+		Ok(DeviceInfo {
+			serial_number: read(Prop_SerialNumber_String)?,
+			model_number: read(Prop_ModelNumber_String)?,
+			manufacturer_name: read(Prop_ManufacturerName_String)?,
+			render_model_name: read(Prop_RenderModelName_String)?,
+		})
+	}
+
+	/// Same as [`Self::get_tracked_device_property()`], but returns `default` if
+	/// the property could not be read for any reason.
+	pub fn get_property_or<'ret, 'manager: 'ret, T: TrackedDeviceProperty<'ret>>(
+		&'manager mut self,
+		index: TrackedDeviceIndex,
+		prop: sys::ETrackedDeviceProperty,
+		default: T,
+	) -> T {
+		self.get_tracked_device_property(index, prop)
+			.unwrap_or(default)
+	}
+
+	/// Gets the kind of device at `index`, which is
+	/// [`TrackedDeviceClass::Invalid`] if there is none.
+	///
+	/// Wraps c++ `GetTrackedDeviceClass`.
+	pub fn tracked_device_class(
+		&mut self,
+		index: TrackedDeviceIndex,
+	) -> TrackedDeviceClass {
+		unsafe { self.inner.as_mut().GetTrackedDeviceClass(index.0) }.into()
+	}
+
+	/// Wraps c++ `IsTrackedDeviceConnected`.
+	pub fn is_tracked_device_connected(&mut self, index: TrackedDeviceIndex) -> bool {
+		unsafe { self.inner.as_mut().IsTrackedDeviceConnected(index.0) }
+	}
+
+	/// Gets the device that currently has `role`, such as the left hand
+	/// controller, or `None` if no device has it.
+	///
+	/// Wraps c++ `GetTrackedDeviceIndexForControllerRole`.
+	pub fn tracked_device_index_for_controller_role(
+		&mut self,
+		role: ControllerRole,
+	) -> Option<TrackedDeviceIndex> {
+		let index = unsafe {
+			self.inner
+				.as_mut()
+				.GetTrackedDeviceIndexForControllerRole(role.into())
+		};
+		TrackedDeviceIndex::new(index).ok()
+	}
+
+	/// Gets the role of the device at `index`, which is
+	/// [`ControllerRole::Invalid`] if it isn't a controller.
+	///
+	/// Wraps c++ `GetControllerRoleForTrackedDeviceIndex`.
+	pub fn controller_role_for_tracked_device_index(
+		&mut self,
+		index: TrackedDeviceIndex,
+	) -> ControllerRole {
+		unsafe {
+			self.inner
+				.as_mut()
+				.GetControllerRoleForTrackedDeviceIndex(index.0)
+		}
+		.into()
+	}
+
+	/// Gets the projection matrix of `eye`, for the given near and far clip
+	/// distances in meters.
+	///
+	/// Wraps c++ `GetProjectionMatrix`.
+	pub fn projection_matrix(
+		&mut self,
+		eye: Eye,
+		near_z: f32,
+		far_z: f32,
+	) -> Matrix4x4 {
+		unsafe {
+			self.inner
+				.as_mut()
+				.GetProjectionMatrix(eye.into(), near_z, far_z)
+		}
+		.into()
+	}
+
+	/// Gets the transform from `eye` to the head, which includes the eye's offset
+	/// from the center of the HMD.
+	///
+	/// Wraps c++ `GetEyeToHeadTransform`.
+	pub fn eye_to_head_transform(&mut self, eye: Eye) -> Matrix3x4 {
+		unsafe { self.inner.as_mut().GetEyeToHeadTransform(eye.into()) }.into()
+	}
+
+	/// Wraps c++ `GetTrackedDeviceActivityLevel`.
+	pub fn get_device_activity_level(
+		&mut self,
+		index: TrackedDeviceIndex,
+	) -> DeviceActivityLevel {
+		unsafe { self.inner.as_mut().GetTrackedDeviceActivityLevel(index.0) }.into()
+	}
+
+	/// Whether the user is currently interacting with the HMD, which usually means
+	/// that they are wearing it.
+	pub fn is_user_interacting(&mut self) -> bool {
+		self.get_device_activity_level(TrackedDeviceIndex::HMD)
+			== DeviceActivityLevel::UserInteraction
+	}
+
+	/// Gets the transform from the seated universe to the standing universe.
+	///
+	/// Wraps c++ `GetSeatedZeroPoseToStandingAbsoluteTrackingPose`.
+	pub fn get_seated_zero_pose_to_standing(&mut self) -> Matrix3x4 {
+		unsafe {
+			self.inner
+				.as_mut()
+				.GetSeatedZeroPoseToStandingAbsoluteTrackingPose()
+		}
+		.into()
+	}
+
+	/// Gets the transform from the raw (uncalibrated) universe to the standing
+	/// universe.
+	///
+	/// Wraps c++ `GetRawZeroPoseToStandingAbsoluteTrackingPose`.
+	pub fn get_raw_zero_pose_to_standing(&mut self) -> Matrix3x4 {
+		unsafe {
+			self.inner
+				.as_mut()
+				.GetRawZeroPoseToStandingAbsoluteTrackingPose()
+		}
+		.into()
+	}
+
+	/// Gets the legacy (non action-based) button and axis state of a controller.
+	/// Returns `None` if the device is not a valid controller.
+	///
+	/// Wraps c++ `GetControllerState`.
+	pub fn get_controller_state(
+		&mut self,
+		index: TrackedDeviceIndex,
+	) -> Option<ControllerState> {
+		let mut state: MaybeUninit<sys::VRControllerState001_t> = MaybeUninit::uninit();
+		let valid = unsafe {
+			self.inner.as_mut().GetControllerState(
+				index.0,
+				state.as_mut_ptr(),
+				std::mem::size_of::<sys::VRControllerState001_t>() as u32,
+			)
+		};
+		valid.then(|| ControllerState(unsafe { state.assume_init() }))
+	}
+
+	/// Gets the legacy controller state together with the device pose, read at the
+	/// same instant. Returns `None` if the device is not a valid controller.
+	///
+	/// Wraps c++ `GetControllerStateWithPose`.
+	pub fn controller_state_with_pose(
+		&mut self,
+		origin: TrackingUniverseOrigin,
+		index: TrackedDeviceIndex,
+	) -> Option<(ControllerState, TrackedDevicePose)> {
+		let mut state: MaybeUninit<sys::VRControllerState001_t> = MaybeUninit::uninit();
+		let mut pose: MaybeUninit<sys::TrackedDevicePose_t> = MaybeUninit::uninit();
+		let valid = unsafe {
+			self.inner.as_mut().GetControllerStateWithPose(
+				origin,
+				index.0,
+				state.as_mut_ptr(),
+				std::mem::size_of::<sys::VRControllerState001_t>() as u32,
+				pose.as_mut_ptr(),
+			)
+		};
+		if !valid {
+			return None;
+		}
+		let (state, pose) = unsafe { (state.assume_init(), pose.assume_init()) };
+		Some((ControllerState(state), TrackedDevicePose(pose)))
+	}
+
+	/// Pops the next event off the queue, or returns `None` if it is empty. Call
+	/// this in a loop until it returns `None`, so that events don't pile up.
+	///
+	/// Wraps c++ `PollNextEvent`.
+	pub fn poll_next_event(&mut self) -> Option<SystemEvent> {
+		let mut raw = RawEvent::zeroed();
+		let has_event = unsafe {
+			self.inner
+				.as_mut()
+				.PollNextEvent((&mut raw as *mut RawEvent).cast(), RawEvent::SIZE)
+		};
+		has_event.then(|| SystemEvent::from_raw(&raw))
+	}
+
+	/// Tells SteamVR that the application received [`SystemEventKind::Quit`] and is
+	/// about to exit. Without this, SteamVR waits a few seconds before killing the
+	/// application.
+	///
+	/// The recommended way to handle quitting is to drain
+	/// [`Self::poll_next_event()`] every frame, and on [`SystemEventKind::Quit`]
+	/// call this, drop all managers and call [`Context::shutdown()`]. An application
+	/// that doesn't acknowledge in time gets killed, which leaves its overlays
+	/// behind until SteamVR notices.
+	///
+	/// Wraps c++ `AcknowledgeQuit_Exiting`.
+	pub fn acknowledge_quit_exiting(&mut self) {
+		unsafe { self.inner.as_mut().AcknowledgeQuit_Exiting() }
+	}
+
+	/// Computes how far into the future the photons of the next frame will hit the
+	/// user's eyes, to pass as the prediction time of
+	/// [`Self::get_device_to_absolute_tracking_pose()`]:
+	///
+	/// `frame_duration - seconds_since_last_vsync + seconds_from_vsync_to_photons`
+	///
+	/// where `frame_duration` is `1 / Prop_DisplayFrequency_Float` and the last term
+	/// is `Prop_SecondsFromVsyncToPhotons_Float`, both read from the HMD.
+	///
+	/// Rather than a plain `f32`, this returns `Ok(None)` if there is no vsync
+	/// timing yet, or the HMD reports a display frequency that isn't positive, as
+	/// any number would be made up in those cases. Errors reading the properties
+	/// are returned as-is.
+	pub fn predicted_seconds_to_photons(&mut self) -> PropResult<Option<f32>> {
+		let mut since_vsync = 0.0;
+		let mut frame_counter = 0u64;
+		let has_vsync = unsafe {
+			self.inner
+				.as_mut()
+				.GetTimeSinceLastVsync(&mut since_vsync, &mut frame_counter)
+		};
+		if !has_vsync {
+			return Ok(None);
+		}
+		let frequency: f32 = self.get_tracked_device_property(
+			TrackedDeviceIndex::HMD,
+			sys::ETrackedDeviceProperty::Prop_DisplayFrequency_Float,
+		)?;
+		let vsync_to_photons: f32 = self.get_tracked_device_property(
+			TrackedDeviceIndex::HMD,
+			sys::ETrackedDeviceProperty::Prop_SecondsFromVsyncToPhotons_Float,
+		)?;
+		Ok(seconds_to_photons(frequency, since_vsync, vsync_to_photons))
+	}
+
+	/// Reads the poses of all devices, predicted `predicted_seconds_from_now` into
+	/// the future. Use 0 for no prediction. The array is indexed by
+	/// [`TrackedDeviceIndex`]; check [`TrackedDevicePose::device_is_connected()`]
+	/// and [`TrackedDevicePose::pose_is_valid()`] before using a pose.
+	///
+	/// Wraps c++ `GetDeviceToAbsoluteTrackingPose`.
+	pub fn get_device_to_absolute_tracking_pose(
+		&mut self,
+		origin: TrackingUniverseOrigin,
+		predicted_seconds_from_now: f32,
+	) -> [TrackedDevicePose; TrackedDeviceIndex::MAX] {
+		let mut poses: MaybeUninit<
+			[sys::TrackedDevicePose_t; TrackedDeviceIndex::MAX],
+		> = MaybeUninit::uninit();
+		unsafe {
+			self.inner.as_mut().GetDeviceToAbsoluteTrackingPose(
+				origin,
+				predicted_seconds_from_now,
+				poses.as_mut_ptr().cast(),
+				TrackedDeviceIndex::MAX as u32,
+			)
+		};
+		unsafe { poses.assume_init() }.map(TrackedDevicePose)
+	}
+
+	/// Reads the pose of a single device, predicted `predicted_seconds` into the
+	/// future. Use 0 for no prediction. Returns `None` if `index` is out of range.
+	///
+	/// Wraps c++ `GetDeviceToAbsoluteTrackingPose`.
+	pub fn pose_for_device_predicted(
+		&mut self,
+		index: TrackedDeviceIndex,
+		origin: TrackingUniverseOrigin,
+		predicted_seconds: f32,
+	) -> Option<TrackedDevicePose> {
+		if index.0 as usize >= TrackedDeviceIndex::MAX {
+			return None;
+		}
+		// OpenVR fills poses in device order, so only read up to `index`.
+		let count = index.0 as usize + 1;
+		let mut poses: Vec<MaybeUninit<sys::TrackedDevicePose_t>> =
+			(0..count).map(|_| MaybeUninit::uninit()).collect();
+		unsafe {
+			self.inner.as_mut().GetDeviceToAbsoluteTrackingPose(
+				origin,
+				predicted_seconds,
+				poses.as_mut_ptr().cast(),
+				count as u32,
+			)
+		};
+		let pose = unsafe { poses[index.0 as usize].assume_init_read() };
+		Some(TrackedDevicePose(pose))
+	}
 }
 unsafe impl Send for SystemManager<'_> {}
-unsafe impl Sync for SystemManager<'_> {}
 
 #[cfg(test)]
 mod test {
 	use super::*;
+
+	#[test]
+	fn decode_chaperone_event() {
+		let mut raw = RawEvent::zeroed();
+		raw.event_type = sys::EVREventType::VREvent_ChaperoneUniverseHasChanged as u32;
+		raw.data[0] = 3;
+		raw.data[1] = 5;
+		assert_eq!(
+			SystemEvent::from_raw(&raw).kind,
+			SystemEventKind::ChaperoneUniverseHasChanged {
+				previous_universe: 3,
+				current_universe: 5,
+			}
+		);
+	}
+
+	#[test]
+	fn seconds_to_photons_needs_a_frequency() {
+		let predicted = seconds_to_photons(100., 0.004, 0.011).unwrap();
+		assert!((predicted - 0.017).abs() < 1e-6, "{predicted}");
+		for frequency in [0., -90., f32::NAN] {
+			assert_eq!(seconds_to_photons(frequency, 0., 0.), None, "{frequency}");
+		}
+	}
+
+	#[test]
+	fn not_yet_available_is_none() {
+		use sys::ETrackedPropertyError::*;
+		let err = |e| ETrackedPropertyError::new(e).unwrap_err();
+
+		assert_eq!(not_yet_available_as_none(Ok(1.5f32)), Ok(Some(1.5)));
+		assert_eq!(
+			not_yet_available_as_none::<f32>(Err(err(TrackedProp_NotYetAvailable))),
+			Ok(None)
+		);
+		assert_eq!(
+			not_yet_available_as_none::<f32>(Err(err(TrackedProp_UnknownProperty))),
+			Err(err(TrackedProp_UnknownProperty))
+		);
+	}
+
 	fn _compile_test(mut system: SystemManager) {
 		// let _bootloader_version =
 		//     system.get_tracked_device_property(TrackedDeviceIndex::HMD, props::DisplayBootloaderVersion);
@@ -171,11 +984,22 @@ mod test {
 				sys::ETrackedDeviceProperty::Prop_DisplayHardwareVersion_Uint64,
 			)
 			.unwrap();
+		let _battery: f32 = system.get_property_or(
+			TrackedDeviceIndex::HMD,
+			sys::ETrackedDeviceProperty::Prop_DeviceBatteryPercentage_Float,
+			0.0,
+		);
 		let _gc_image_cstring: &CStr = system
 			.get_tracked_device_property(
 				TrackedDeviceIndex::HMD,
 				sys::ETrackedDeviceProperty::Prop_DisplayGCImage_String,
 			)
 			.unwrap();
+		let _frame_rates: Vec<f32> = system
+			.get_tracked_device_property_array(
+				TrackedDeviceIndex::HMD,
+				props::FloatArray::DisplayAvailableFrameRates,
+			)
+			.unwrap();
 	}
 }
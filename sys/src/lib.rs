@@ -15,26 +15,58 @@ include_cpp! {
 	generate!("vr::VR_Init")
 	generate_pod!("vr::EVRApplicationType")
 	generate!("vr::VR_Shutdown")
+	generate!("vr::VR_GetGenericInterface")
+	generate!("vr::VR_GetRuntimePath")
 
 	generate!("vr::IVRSystem")
 	generate!("vr::VRSystem")
 	generate!("vr::k_unMaxPropertyStringSize")
+	generate!("vr::k_unFloatPropertyTag")
+	generate!("vr::k_unInt32PropertyTag")
+	generate!("vr::k_unHmdMatrix34PropertyTag")
+	generate!("vr::k_unHmdVector3PropertyTag")
+	generate!("vr::k_unHmdVector4PropertyTag")
+	generate_pod!("vr::VRControllerState001_t")
+	generate_pod!("vr::ETrackedDeviceClass")
+	generate_pod!("vr::ETrackedControllerRole")
+	generate_pod!("vr::EDeviceActivityLevel")
+	generate!("vr::VREvent_t")
+	generate_pod!("vr::EVREventType")
 
 	generate!("vr::IVROverlay")
 	generate!("vr::VROverlay")
 	generate_pod!("vr::EVROverlayError")
 	generate_pod!("vr::VROverlayHandle_t")
+	generate_pod!("vr::VROverlayFlags")
+	generate_pod!("vr::VROverlayInputMethod")
+	generate_pod!("vr::EGamepadTextInputMode")
+	generate_pod!("vr::EGamepadTextInputLineMode")
+	generate_pod!("vr::VROverlayIntersectionParams_t")
+	generate_pod!("vr::VROverlayIntersectionResults_t")
+	generate_pod!("vr::HmdVector3_t")
+	generate_pod!("vr::HmdVector2_t")
+	generate_pod!("vr::HmdVector4_t")
+
+	generate!("vr::IVRChaperone")
+	generate!("vr::VRChaperone")
 
 	generate!("vr::IVRChaperoneSetup")
 	generate!("vr::VRChaperoneSetup")
+	generate_pod!("vr::EChaperoneConfigFile")
 
 	generate!("vr::VR_GetVRInitErrorAsSymbol")
 	generate_pod!("vr::EVRInitError")
 
 	generate_pod!("vr::ETrackingUniverseOrigin")
-	generate!("vr::HmdMatrix34_t")
+	generate_pod!("vr::ETrackingResult")
+	generate_pod!("vr::EVREye")
+	generate_pod!("vr::HmdMatrix34_t")
+	generate_pod!("vr::HmdMatrix44_t")
 
 	generate_pod!("vr::VRTextureBounds_t")
+	generate_pod!("vr::Texture_t")
+	generate_pod!("vr::ETextureType")
+	generate_pod!("vr::EColorSpace")
 
 	// input
 	generate!("vr::IVRInput")
@@ -44,15 +76,32 @@ include_cpp! {
 	generate_pod!("vr::VRActionHandle_t")
 	generate_pod!("vr::VRInputValueHandle_t")
 	generate_pod!("vr::VRActiveActionSet_t")
+	generate!("vr::k_nActionSetOverlayGlobalPriorityMin")
+	generate!("vr::k_nActionSetOverlayGlobalPriorityMax")
+	generate!("vr::k_nActionSetPriorityReservedMin")
 	generate_pod!("vr::InputDigitalActionData_t")
+	generate_pod!("vr::InputAnalogActionData_t")
 	generate_pod!("vr::TrackedDevicePose_t")
 	generate_pod!("vr::InputPoseActionData_t")
 	generate_pod!("vr::InputOriginInfo_t")
+	generate_pod!("vr::VRBoneTransform_t")
+	generate_pod!("vr::EVRSkeletalTransformSpace")
+	generate_pod!("vr::EVRSkeletalMotionRange")
+	generate_pod!("vr::VRSkeletalSummaryData_t")
+	generate_pod!("vr::EVRSummaryType")
+	generate!("vr::k_unMaxActionOriginCount")
 
 	// applications
 	generate!("vr::IVRApplications")
 	generate!("vr::VRApplications")
 	generate_pod!("vr::EVRApplicationError")
+	generate_pod!("vr::EVRSceneApplicationState")
+	generate!("vr::k_unMaxApplicationKeyLength")
+
+	// settings
+	generate!("vr::IVRSettings")
+	generate!("vr::VRSettings")
+	generate_pod!("vr::EVRSettingsError")
 }
 
 //pub use ffi::vr::*;